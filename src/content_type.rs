@@ -0,0 +1,80 @@
+//! Rough classification of entries for the type filter.
+
+use crate::convert;
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ContentType {
+    Text,
+    Url,
+    Code,
+    Color,
+}
+
+impl ContentType {
+    pub const ALL: [ContentType; 4] = [ContentType::Text, ContentType::Url, ContentType::Code, ContentType::Color];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ContentType::Text => "Text",
+            ContentType::Url => "URL",
+            ContentType::Code => "Code",
+            ContentType::Color => "Color",
+        }
+    }
+}
+
+/// Guess what `content` is. Anything that isn't clearly a URL, color or code is text.
+pub fn classify(content: &str) -> ContentType {
+    let trimmed = content.trim();
+    if convert::parse_color(trimmed).is_some() {
+        return ContentType::Color;
+    }
+
+    if is_url(trimmed) {
+        return ContentType::Url;
+    }
+
+    if looks_like_code(trimmed) {
+        ContentType::Code
+    } else {
+        ContentType::Text
+    }
+}
+
+/// A single token starting with a web or FTP scheme, or `www.`.
+pub fn is_url(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    !text.is_empty()
+        && !text.contains(char::is_whitespace)
+        && ["http://", "https://", "ftp://", "www."].iter().any(|prefix| lower.starts_with(prefix))
+}
+
+/// The URLs in content with several lines, most of them URLs. Other lines are
+/// skipped.
+pub fn links(content: &str) -> Option<Vec<&str>> {
+    let lines: Vec<&str> = content.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    let urls: Vec<&str> = lines.iter().copied().filter(|line| is_url(line)).collect();
+    (urls.len() > 1 && urls.len() * 2 > lines.len()).then_some(urls)
+}
+
+/// Code when most non-empty lines end like a statement or block, or are indented
+/// beneath one. A single line only counts if it ends like a statement.
+fn looks_like_code(content: &str) -> bool {
+    let lines: Vec<&str> = content.lines().filter(|line| !line.trim().is_empty()).collect();
+    let code_like = lines.iter()
+        .filter(|line| {
+            let end = line.trim_end();
+            end.ends_with([';', '{', '}', ')', ']', ':', ','])
+                || line.starts_with(['\t', ' '])
+                || end.starts_with("//")
+                || end.starts_with('#')
+        })
+        .count();
+
+    match lines.len() {
+        0 => false,
+        1 => lines[0].trim_end().ends_with([';', '{']),
+        total => code_like * 2 > total,
+    }
+}
@@ -0,0 +1,331 @@
+//! Content-type-aware conversions offered in an entry's "Copy as" menu.
+
+/// Alternative representations of `content`, as `(label, converted)` pairs.
+/// Empty when the content isn't recognised as a number, color or timestamp.
+pub fn conversions(content: &str) -> Vec<(&'static str, String)> {
+    let content = content.trim();
+    let mut result = Vec::new();
+
+    if let Some(number) = parse_number(content) {
+        result.push(("Decimal", number.to_string()));
+        result.push(("Hex", format_radix(number, "0x", |n| format!("{:x}", n))));
+        result.push(("Binary", format_radix(number, "0b", |n| format!("{:b}", n))));
+
+        if let Some(iso) = timestamp_to_iso8601(number) {
+            result.push(("ISO-8601", iso));
+        }
+    }
+
+    if let Some((r, g, b)) = parse_color(content) {
+        let (h, s, l) = rgb_to_hsl(r, g, b);
+        result.push(("Hex color", format!("#{:02x}{:02x}{:02x}", r, g, b)));
+        result.push(("RGB", format!("rgb({}, {}, {})", r, g, b)));
+        result.push(("HSL", format!("hsl({}, {}%, {}%)", h, s, l)));
+    }
+
+    // Don't offer a conversion that gives back exactly what is stored
+    result.retain(|(_, converted)| converted != content);
+    result
+}
+
+/// Parse a decimal, `0x` hex or `0b` binary integer.
+pub fn parse_number(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+
+    let value = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        parse_digits(hex, 16)?
+    } else if let Some(binary) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+        parse_digits(binary, 2)?
+    } else if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()?
+    } else {
+        return None;
+    };
+
+    if negative { value.checked_neg() } else { Some(value) }
+}
+
+/// Unsigned digits in `radix`. from_str_radix alone would accept a sign, as in "0x-5".
+fn parse_digits(digits: &str, radix: u32) -> Option<i64> {
+    if digits.starts_with(['+', '-']) {
+        return None;
+    }
+    i64::from_str_radix(digits, radix).ok()
+}
+
+fn format_radix(number: i64, prefix: &str, digits: impl Fn(u64) -> String) -> String {
+    let sign = if number < 0 { "-" } else { "" };
+    format!("{}{}{}", sign, prefix, digits(number.unsigned_abs()))
+}
+
+/// Parse `#rgb`, `#rrggbb`, `rgb(r, g, b)` or `hsl(h, s%, l%)`.
+pub fn parse_color(text: &str) -> Option<(u8, u8, u8)> {
+    let lower = text.to_ascii_lowercase();
+
+    if let Some(hex) = lower.strip_prefix('#') {
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        return match hex.len() {
+            3 => {
+                let channel = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok().map(|v| v * 17);
+                Some((channel(0)?, channel(1)?, channel(2)?))
+            }
+            6 => {
+                let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+                Some((channel(0)?, channel(2)?, channel(4)?))
+            }
+            _ => None,
+        };
+    }
+
+    if let Some(args) = function_args(&lower, "rgb") {
+        let [r, g, b] = args.as_slice() else {
+            return None;
+        };
+        return Some((r.parse().ok()?, g.parse().ok()?, b.parse().ok()?));
+    }
+
+    if let Some(args) = function_args(&lower, "hsl") {
+        let [h, s, l] = args.as_slice() else {
+            return None;
+        };
+        let h: f64 = h.trim_end_matches("deg").parse().ok()?;
+        let s: f64 = s.trim_end_matches('%').parse().ok()?;
+        let l: f64 = l.trim_end_matches('%').parse().ok()?;
+        if !(0.0..=100.0).contains(&s) || !(0.0..=100.0).contains(&l) {
+            return None;
+        }
+        return Some(hsl_to_rgb(h, s / 100.0, l / 100.0));
+    }
+
+    None
+}
+
+/// Comma-separated arguments of `name(...)`, trimmed.
+fn function_args<'a>(text: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let inner = text.strip_prefix(name)?.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+    Some(inner.split(',').map(str::trim).collect())
+}
+
+/// Convert RGB to HSL with hue in degrees and saturation/lightness in percent, rounded.
+pub fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0, 0, (l * 100.0).round() as u8);
+    }
+
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    ((h.round() as u16) % 360, (s * 100.0).round() as u8, (l * 100.0).round() as u8)
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+/// Format a Unix timestamp as ISO-8601 UTC. Only plausible timestamps are
+/// accepted: 10 digits are read as seconds, 13 digits as milliseconds.
+pub fn timestamp_to_iso8601(value: i64) -> Option<String> {
+    let (secs, millis) = match value {
+        1_000_000_000..=9_999_999_999 => (value, None),
+        1_000_000_000_000..=9_999_999_999_999 => (value / 1000, Some(value % 1000)),
+        _ => return None,
+    };
+
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let time = secs.rem_euclid(86_400);
+    let (hour, minute, second) = (time / 3600, time % 3600 / 60, time % 60);
+
+    Some(match millis {
+        Some(ms) => format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z", year, month, day, hour, minute, second, ms),
+        None => format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hour, minute, second),
+    })
+}
+
+/// Fill in `{YYYYMMDD}`, `{HHMMSS}` and `{YYYYMMDD-HHMMSS}` in a file name
+/// template with the date and time of `secs`, a wall-clock time such as
+/// `local_time::wall_clock` gives.
+pub fn expand_time_template(template: &str, secs: u64) -> String {
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let time = secs % 86_400;
+    let date = format!("{:04}{:02}{:02}", year, month, day);
+    let clock = format!("{:02}{:02}{:02}", time / 3600, time % 3600 / 60, time % 60);
+    template
+        .replace("{YYYYMMDD-HHMMSS}", &format!("{}-{}", date, clock))
+        .replace("{YYYYMMDD}", &date)
+        .replace("{HHMMSS}", &clock)
+}
+
+/// Parse a `YYYY-MM-DD` date into days since 1970-01-01.
+pub fn parse_date(text: &str) -> Option<i64> {
+    let mut parts = text.trim().splitn(3, '-');
+    let year = parts.next()?.parse::<i64>().ok()?;
+    let month = parts.next()?.parse::<u32>().ok().filter(|m| (1..=12).contains(m))?;
+    let day = parts.next()?.parse::<u32>().ok().filter(|d| (1..=31).contains(d))?;
+    let days = days_from_civil(year, month, day);
+    // Reject dates like 02-30 that roll over into the next month
+    (civil_from_days(days) == (year, month, day)).then_some(days)
+}
+
+/// Convert a proleptic Gregorian date to days since 1970-01-01 (Howard Hinnant's
+/// `days_from_civil`, the inverse of `civil_from_days`).
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 } as i64;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) date in the proleptic
+/// Gregorian calendar (Howard Hinnant's `civil_from_days`).
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_in_each_base() {
+        assert_eq!(parse_number("255"), Some(255));
+        assert_eq!(parse_number("-42"), Some(-42));
+        assert_eq!(parse_number("0xff"), Some(255));
+        assert_eq!(parse_number("0XFF"), Some(255));
+        assert_eq!(parse_number("0b1010"), Some(10));
+        assert_eq!(parse_number("-0x10"), Some(-16));
+        assert_eq!(parse_number(""), None);
+        assert_eq!(parse_number("12a"), None);
+        assert_eq!(parse_number("0xg1"), None);
+        assert_eq!(parse_number("0b102"), None);
+    }
+
+    #[test]
+    fn rejects_signs_after_the_prefix() {
+        assert_eq!(parse_number("0x-5"), None);
+        assert_eq!(parse_number("0x+5"), None);
+        assert_eq!(parse_number("0b+1"), None);
+        assert_eq!(parse_number("-0b-1"), None);
+        assert_eq!(parse_number("--5"), None);
+        assert_eq!(parse_number("-0x-8000000000000000"), None);
+    }
+
+    #[test]
+    fn handles_the_ends_of_the_range() {
+        assert_eq!(parse_number("9223372036854775807"), Some(i64::MAX));
+        assert_eq!(parse_number("-0x7fffffffffffffff"), Some(-i64::MAX));
+        // i64::MIN's magnitude doesn't fit before negating
+        assert_eq!(parse_number("-9223372036854775808"), None);
+        assert_eq!(parse_number("0x8000000000000000"), None);
+    }
+
+    #[test]
+    fn offers_other_bases_but_not_the_original() {
+        let converted = conversions("0x1f");
+        assert!(converted.contains(&("Decimal", "31".to_string())));
+        assert!(converted.contains(&("Binary", "0b11111".to_string())));
+        assert!(!converted.iter().any(|(label, _)| *label == "Hex"));
+        assert!(conversions("hello").is_empty());
+    }
+
+    #[test]
+    fn parses_colors() {
+        assert_eq!(parse_color("#fff"), Some((255, 255, 255)));
+        assert_eq!(parse_color("#1A2b3C"), Some((0x1a, 0x2b, 0x3c)));
+        assert_eq!(parse_color("rgb(10, 20, 30)"), Some((10, 20, 30)));
+        assert_eq!(parse_color("RGB( 0,0,0 )"), Some((0, 0, 0)));
+        assert_eq!(parse_color("hsl(0, 100%, 50%)"), Some((255, 0, 0)));
+        assert_eq!(parse_color("#ffff"), None);
+        assert_eq!(parse_color("#ggg"), None);
+        assert_eq!(parse_color("rgb(256, 0, 0)"), None);
+        assert_eq!(parse_color("rgb(1, 2)"), None);
+    }
+
+    #[test]
+    fn converts_rgb_to_hsl() {
+        assert_eq!(rgb_to_hsl(255, 0, 0), (0, 100, 50));
+        assert_eq!(rgb_to_hsl(0, 0, 255), (240, 100, 50));
+        assert_eq!(rgb_to_hsl(128, 128, 128), (0, 0, 50));
+    }
+
+    #[test]
+    fn formats_timestamps_as_iso8601() {
+        assert_eq!(timestamp_to_iso8601(1_700_000_000).as_deref(), Some("2023-11-14T22:13:20Z"));
+        assert_eq!(timestamp_to_iso8601(1_700_000_000_123).as_deref(), Some("2023-11-14T22:13:20.123Z"));
+        // Leap day
+        assert_eq!(timestamp_to_iso8601(1_709_164_800).as_deref(), Some("2024-02-29T00:00:00Z"));
+        // Too short or long to be a plausible timestamp
+        assert_eq!(timestamp_to_iso8601(12_345), None);
+        assert_eq!(timestamp_to_iso8601(-1_700_000_000), None);
+    }
+
+    #[test]
+    fn civil_dates_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(days_from_civil(1900, 3, 1), -25_508);
+        assert_eq!(days_from_civil(2000, 2, 29), 11_016);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        for days in (-800_000..800_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn parses_dates_and_rejects_impossible_ones() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("1969-12-31"), Some(-1));
+        assert_eq!(parse_date("2024-02-29"), Some(19_782));
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
+    #[test]
+    fn expands_time_templates() {
+        assert_eq!(expand_time_template("x-{YYYYMMDD-HHMMSS}", 1_700_000_000), "x-20231114-221320");
+        assert_eq!(expand_time_template("{YYYYMMDD}/{HHMMSS}", 0), "19700101/000000");
+    }
+}
@@ -0,0 +1,149 @@
+//! System-wide hotkeys that work while another app has focus.
+//!
+//! rdev reports every key event on the system. The listener only tracks which
+//! modifiers are held and compares key presses against the configured chord;
+//! nothing else is kept or logged. Unlike in-app shortcuts, `Ctrl` here is the
+//! Control key on every platform and `Cmd`/`Super` is the Command/Windows key.
+
+use eframe::egui::Context;
+use rdev::{EventType, Key};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Clone, Copy, PartialEq, Default)]
+struct Modifiers {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Chord {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+/// Parse a chord such as `"Ctrl+Alt+V"`. Letters, digits, F1-F12 and a few named
+/// keys are supported, always with at least one modifier.
+pub fn parse_chord(chord: &str) -> Result<Chord, String> {
+    let mut modifiers = Modifiers::default();
+    let mut key = None;
+
+    for part in chord.split('+').map(str::trim) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers.ctrl = true,
+            "alt" | "option" => modifiers.alt = true,
+            "shift" => modifiers.shift = true,
+            "cmd" | "command" | "super" | "win" | "meta" => modifiers.meta = true,
+            _ if key.is_some() => return Err(format!("\"{}\" has more than one key", chord)),
+            name => key = Some(key_from_name(name).ok_or_else(|| format!("Unknown key \"{}\"", part))?),
+        }
+    }
+
+    let key = key.ok_or_else(|| format!("\"{}\" has no key", chord))?;
+    // A bare key would fire whenever it is typed in any app
+    if modifiers == Modifiers::default() {
+        return Err(format!("\"{}\" needs at least one modifier", chord));
+    }
+    Ok(Chord { modifiers, key })
+}
+
+fn key_from_name(name: &str) -> Option<Key> {
+    const LETTERS: [Key; 26] = [
+        Key::KeyA, Key::KeyB, Key::KeyC, Key::KeyD, Key::KeyE, Key::KeyF, Key::KeyG, Key::KeyH, Key::KeyI,
+        Key::KeyJ, Key::KeyK, Key::KeyL, Key::KeyM, Key::KeyN, Key::KeyO, Key::KeyP, Key::KeyQ, Key::KeyR,
+        Key::KeyS, Key::KeyT, Key::KeyU, Key::KeyV, Key::KeyW, Key::KeyX, Key::KeyY, Key::KeyZ,
+    ];
+    const DIGITS: [Key; 10] = [
+        Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+    ];
+    const FUNCTION_KEYS: [Key; 12] = [
+        Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10, Key::F11, Key::F12,
+    ];
+
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_lowercase() {
+            return Some(LETTERS[(c as u8 - b'a') as usize]);
+        }
+        if c.is_ascii_digit() {
+            return Some(DIGITS[(c as u8 - b'0') as usize]);
+        }
+    }
+    if let Some(number) = name.strip_prefix('f').and_then(|n| n.parse::<usize>().ok()) {
+        return FUNCTION_KEYS.get(number.checked_sub(1)?).copied();
+    }
+
+    Some(match name {
+        "space" => Key::Space,
+        "insert" => Key::Insert,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "comma" | "," => Key::Comma,
+        "period" | "." => Key::Dot,
+        "backquote" | "`" => Key::BackQuote,
+        _ => return None,
+    })
+}
+
+/// A background listener that reports presses of one configurable chord.
+pub struct GlobalHotkey {
+    chord: Arc<Mutex<Option<Chord>>>,
+    pressed: Receiver<()>,
+}
+
+impl GlobalHotkey {
+    /// Start listening. rdev can't stop a listener, so this should be called once
+    /// and the chord changed with `set_chord`.
+    pub fn start(ctx: Context, chord: Option<Chord>) -> Self {
+        let (sender, pressed) = channel();
+        let chord = Arc::new(Mutex::new(chord));
+
+        let listener_chord = Arc::clone(&chord);
+        thread::spawn(move || {
+            let mut held = Modifiers::default();
+            let result = rdev::listen(move |event| match event.event_type {
+                EventType::KeyPress(key) => {
+                    set_modifier(&mut held, key, true);
+                    let target = listener_chord.lock().ok().and_then(|chord| *chord);
+                    if target.is_some_and(|target| target.key == key && target.modifiers == held) {
+                        let _ = sender.send(());
+                        ctx.request_repaint();
+                    }
+                }
+                EventType::KeyRelease(key) => set_modifier(&mut held, key, false),
+                _ => {}
+            });
+            if let Err(e) = result {
+                eprintln!("Global hotkeys unavailable: {:?}", e);
+            }
+        });
+
+        Self { chord, pressed }
+    }
+
+    pub fn set_chord(&self, chord: Option<Chord>) {
+        if let Ok(mut current) = self.chord.lock() {
+            *current = chord;
+        }
+    }
+
+    /// How many times the chord was pressed since the last call.
+    pub fn presses(&self) -> usize {
+        self.pressed.try_iter().count()
+    }
+}
+
+fn set_modifier(held: &mut Modifiers, key: Key, down: bool) {
+    match key {
+        Key::ControlLeft | Key::ControlRight => held.ctrl = down,
+        Key::Alt | Key::AltGr => held.alt = down,
+        Key::ShiftLeft | Key::ShiftRight => held.shift = down,
+        Key::MetaLeft | Key::MetaRight => held.meta = down,
+        _ => {}
+    }
+}
@@ -0,0 +1,447 @@
+//! UI translations.
+//!
+//! Strings are looked up by their English text, so untranslated strings (and
+//! English itself) need no catalog entry: `tr` falls back to the key.
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::sync::{OnceLock, RwLock};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// The language's own name, so it can be found without reading the current one.
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn catalog(self) -> Option<&'static HashMap<&'static str, &'static str>> {
+        static GERMAN: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+        match self {
+            Language::English => None,
+            Language::German => Some(GERMAN.get_or_init(|| GERMAN_STRINGS.iter().copied().collect())),
+        }
+    }
+}
+
+static CURRENT: RwLock<Language> = RwLock::new(Language::English);
+
+pub fn set_language(language: Language) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = language;
+    }
+}
+
+/// Translate an English UI string into the current language.
+pub fn tr(english: &'static str) -> &'static str {
+    let language = CURRENT.read().map_or(Language::English, |current| *current);
+    language.catalog()
+        .and_then(|catalog| catalog.get(english).copied())
+        .unwrap_or(english)
+}
+
+/// Translate a UI string with `{}` placeholders and fill them with `args` in
+/// order. Translations can move the placeholders but not reorder them.
+pub fn tr_format(english: &'static str, args: &[&dyn Display]) -> String {
+    let mut parts = tr(english).split("{}");
+    let mut result = parts.next().unwrap_or_default().to_string();
+    for (index, part) in parts.enumerate() {
+        if let Some(arg) = args.get(index) {
+            let _ = write!(result, "{}", arg);
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+const GERMAN_STRINGS: &[(&str, &str)] = &[
+    // Header
+    ("CopyCat Clipboard Manager", "CopyCat Zwischenablage-Manager"),
+    ("Memory only: history is not saved", "Nur im Speicher: Verlauf wird nicht gespeichert"),
+    ("(memory only)", "(nur im Speicher)"),
+    ("(snoozed, {} left)", "(schlummert, noch {})"),
+    // Search and filters
+    ("Search:", "Suche:"),
+    ("Recent searches", "Letzte Suchen"),
+    ("Clear search history", "Suchverlauf löschen"),
+    ("Favorites only", "Nur Favoriten"),
+    ("Sort:", "Sortierung:"),
+    ("Newest", "Neueste"),
+    ("Oldest", "Älteste"),
+    ("Most used", "Meistgenutzt"),
+    ("Largest", "Größte"),
+    ("Recently favorited", "Zuletzt favorisiert"),
+    ("Favorites first", "Favoriten zuerst"),
+    ("Any time", "Beliebige Zeit"),
+    ("Last hour", "Letzte Stunde"),
+    ("Today", "Heute"),
+    ("Yesterday", "Gestern"),
+    ("Last 7 days", "Letzte 7 Tage"),
+    ("Custom…", "Benutzerdefiniert…"),
+    ("from", "von"),
+    ("to", "bis"),
+    ("All", "Alle"),
+    ("Color", "Farbe"),
+    ("shown", "angezeigt"),
+    ("Copy as list", "Als Liste kopieren"),
+    ("Favorite all shown", "Alle angezeigten favorisieren"),
+    ("Delete all shown", "Alle angezeigten löschen"),
+    // History list
+    ("Clipboard History", "Zwischenablage-Verlauf"),
+    ("Nothing copied yet", "Noch nichts kopiert"),
+    ("CopyCat keeps a history of the text you copy while it is running.", "CopyCat führt einen Verlauf des kopierten Textes, solange es läuft."),
+    ("Copy something in any app and it will show up here.", "Was in einer beliebigen App kopiert wird, erscheint hier."),
+    ("Adjust what gets captured in Settings", "In den Einstellungen festlegen, was aufgezeichnet wird"),
+    ("No clipboard entries found", "Keine Einträge gefunden"),
+    ("Locked", "Gesperrt"),
+    ("current", "aktuell"),
+    ("This entry is on the clipboard", "Dieser Eintrag ist in der Zwischenablage"),
+    ("Copy", "Kopieren"),
+    ("Copy with auto-clear", "Kopieren und später leeren"),
+    ("Copy as HTML", "Als HTML kopieren"),
+    ("Copy as plain", "Als reinen Text kopieren"),
+    ("Copy as", "Kopieren als"),
+    ("Copy wrapped", "Umschlossen kopieren"),
+    ("Copy clean URL", "Bereinigte URL kopieren"),
+    ("Open link", "Link öffnen"),
+    ("Open all links", "Alle Links öffnen"),
+    ("Copy entry link", "Link zum Eintrag kopieren"),
+    ("Move to top", "Nach oben verschieben"),
+    ("Lock", "Sperren"),
+    ("Unlock", "Entsperren"),
+    ("Delete", "Löschen"),
+    ("Deleted entry (Ctrl+Z to undo)", "Eintrag gelöscht (Strg+Z zum Rückgängigmachen)"),
+    ("History is full of locked entries; unlock some to capture more", "Verlauf voller gesperrter Einträge; zum Aufnehmen einige entsperren"),
+    ("Unlock the entry to delete it", "Entsperren, um den Eintrag zu löschen"),
+    ("Mark favorite", "Als Favorit markieren"),
+    ("Unmark favorite", "Favorit entfernen"),
+    // Footer
+    ("Clear All", "Alles löschen"),
+    ("Clear Non-Favorites", "Nicht-Favoriten löschen"),
+    ("Clear older than…", "Löschen älter als…"),
+    ("24 hours", "24 Stunden"),
+    ("7 days", "7 Tage"),
+    ("30 days", "30 Tage"),
+    (" days", " Tage"),
+    ("{} days", "{} Tage"),
+    ("Clear", "Löschen"),
+    ("Favorites are kept", "Favoriten bleiben erhalten"),
+    ("Settings", "Einstellungen"),
+    ("Archives", "Archive"),
+    ("Statistics", "Statistik"),
+    ("Find similar", "Ähnliche finden"),
+    ("Total entries:", "Einträge gesamt:"),
+    ("Capture paused", "Aufzeichnung pausiert"),
+    ("Capture snoozed", "Aufzeichnung schlummert"),
+    ("Clipboard clears in", "Zwischenablage wird geleert in"),
+    ("Keep", "Behalten"),
+    ("Don't clear the clipboard", "Zwischenablage nicht leeren"),
+    ("Snooze", "Schlummern"),
+    ("Resume now", "Jetzt fortsetzen"),
+    ("Pause capture for a while", "Aufzeichnung für eine Weile pausieren"),
+    // Windows and dialogs
+    ("Ring {}/{}: {}", "Ring {}/{}: {}"),
+    ("Type a command…", "Befehl eingeben…"),
+    ("No matching commands", "Keine passenden Befehle"),
+    ("{} ({} chars, {} lines)", "{} ({} Zeichen, {} Zeilen)"),
+    ("Details", "Details"),
+    ("Close", "Schließen"),
+    ("Pop out", "Abdocken"),
+    ("Show whitespace", "Leerraum anzeigen"),
+    ("Copy selection", "Auswahl kopieren"),
+    ("Lines:", "Zeilen:"),
+    ("Copy lines", "Zeilen kopieren"),
+    ("Entry", "Eintrag"),
+    ("Pin to this entry", "An diesen Eintrag anheften"),
+    ("Keep showing this entry when the selection changes", "Diesen Eintrag weiter anzeigen, wenn sich die Auswahl ändert"),
+    ("Select an entry to show it here", "Einen Eintrag auswählen, um ihn hier anzuzeigen"),
+    ("Captures per day, last {} days (UTC)", "Aufzeichnungen pro Tag, letzte {} Tage (UTC)"),
+    ("Most copied", "Am häufigsten kopiert"),
+    ("Nothing copied from history yet", "Noch nichts aus dem Verlauf kopiert"),
+    ("Clipboard formats", "Formate der Zwischenablage"),
+    ("not available ({})", "nicht verfügbar ({})"),
+    ("{} chars", "{} Zeichen"),
+    ("{} bytes", "{} Bytes"),
+    ("{}×{} pixels", "{}×{} Pixel"),
+    ("{} paths", "{} Pfade"),
+    ("Text", "Text"),
+    ("HTML", "HTML"),
+    ("Image", "Bild"),
+    ("Files", "Dateien"),
+    ("Checked {}s ago, on every poll while open", "Vor {} s geprüft, bei jeder Abfrage, solange geöffnet"),
+    ("Check now", "Jetzt prüfen"),
+    ("Similar entries", "Ähnliche Einträge"),
+    ("similarity", "Ähnlichkeit"),
+    ("Find", "Suchen"),
+    ("Exact duplicates and entries over 2000 characters are ignored", "Exakte Duplikate und Einträge über 2000 Zeichen werden ignoriert"),
+    ("No similar entries found", "Keine ähnlichen Einträge gefunden"),
+    ("No archived history yet", "Noch kein archivierter Verlauf"),
+    ("{} entries (read-only)", "{} Einträge (schreibgeschützt)"),
+    ("Open {} links in the browser?", "{} Links im Browser öffnen?"),
+    ("Open", "Öffnen"),
+    ("Cancel", "Abbrechen"),
+    ("Delete {} entries? This cannot be undone.", "{} Einträge löschen? Das kann nicht rückgängig gemacht werden."),
+    ("CopyCat couldn't read {}: {}", "CopyCat konnte {} nicht lesen: {}"),
+    ("The file won't be overwritten until you choose what to do.", "Die Datei wird erst überschrieben, wenn eine Entscheidung getroffen ist."),
+    ("Restore backup ({} entries)", "Sicherung wiederherstellen ({} Einträge)"),
+    ("Start fresh", "Neu beginnen"),
+    ("Quit", "Beenden"),
+    ("Leave the file alone to recover it by hand", "Die Datei unverändert lassen, um sie von Hand zu retten"),
+    ("Can't save history to {}: {}", "Verlauf kann nicht in {} gespeichert werden: {}"),
+    ("New entries will be lost when CopyCat closes.", "Neue Einträge gehen beim Beenden von CopyCat verloren."),
+    ("Keep history in memory only", "Verlauf nur im Speicher halten"),
+    ("Save to {}", "In {} speichern"),
+    ("Ignore", "Ignorieren"),
+    ("{} min", "{} Min."),
+    ("({} new, shown when the pointer leaves the list)", "({} neu, erscheinen, sobald der Zeiger die Liste verlässt)"),
+    ("{}{}, copied {}", "{}{}, kopiert {}"),
+    ("Favorite: ", "Favorit: "),
+    ("hidden entry", "verborgener Eintrag"),
+    ("Trimmed", "Ohne Leerraum am Rand"),
+    ("UPPERCASE", "GROSSBUCHSTABEN"),
+    ("lowercase", "kleinbuchstaben"),
+    ("Without ANSI codes", "Ohne ANSI-Codes"),
+    ("Tabs→Spaces ({})", "Tabs→Leerzeichen ({})"),
+    ("Spaces ({})→Tabs", "Leerzeichen ({})→Tabs"),
+    ("Decimal", "Dezimal"),
+    ("Hex", "Hex"),
+    ("Binary", "Binär"),
+    ("ISO-8601", "ISO-8601"),
+    ("Hex color", "Hex-Farbe"),
+    ("RGB", "RGB"),
+    ("HSL", "HSL"),
+    ("URL", "URL"),
+    ("Code", "Code"),
+    // Settings
+    ("Language", "Sprache"),
+    ("History file:", "Verlaufsdatei:"),
+    ("Apply", "Übernehmen"),
+    ("Open data folder", "Datenordner öffnen"),
+    ("The folder holding the history file, its backup and archives", "Der Ordner mit der Verlaufsdatei, ihrer Sicherung und den Archiven"),
+    ("Sync with other machines sharing this file", "Mit anderen Rechnern synchronisieren, die diese Datei teilen"),
+    ("Point the history file into a Syncthing/Dropbox folder on each machine", "Die Verlaufsdatei auf jedem Rechner in einen Syncthing-/Dropbox-Ordner legen"),
+    ("Memory only (don't save history to disk)", "Nur im Speicher (Verlauf nicht auf die Festplatte schreiben)"),
+    ("History is lost when CopyCat closes. Turning this off saves the current history.", "Der Verlauf geht beim Beenden von CopyCat verloren. Beim Ausschalten wird der aktuelle Verlauf gespeichert."),
+    ("Import from:", "Importieren aus:"),
+    ("A JSON export (e.g. from a CopyQ script) or timestamp<TAB>text lines", "Ein JSON-Export (z. B. aus einem CopyQ-Skript) oder Zeilen im Format Zeitstempel<TAB>Text"),
+    ("Import", "Importieren"),
+    ("Favorites file:", "Favoritendatei:"),
+    ("Back up just the favorites, or restore them from such a backup", "Nur die Favoriten sichern oder aus einer solchen Sicherung wiederherstellen"),
+    ("Export", "Exportieren"),
+    ("Export file name:", "Name der Exportdatei:"),
+    ("{YYYYMMDD-HHMMSS}, {YYYYMMDD} and {HHMMSS} are the local time of the export, {ext} the file extension", "{YYYYMMDD-HHMMSS}, {YYYYMMDD} und {HHMMSS} sind die Ortszeit des Exports, {ext} die Dateiendung"),
+    ("Restore previous clipboard after \"Copy as\"", "Vorherige Zwischenablage nach \"Kopieren als\" wiederherstellen"),
+    ("Restore after:", "Wiederherstellen nach:"),
+    ("\"Copy with auto-clear\" clears the clipboard after:", "\"Kopieren und später leeren\" leert die Zwischenablage nach:"),
+    ("copies an entry", "kopiert einen Eintrag"),
+    ("Clipboard ring hotkey:", "Tastenkürzel für den Zwischenablage-Ring:"),
+    ("e.g. Ctrl+Alt+V", "z. B. Ctrl+Alt+V"),
+    ("cycles through", "wechselt durch"),
+    (" entries", " Einträge"),
+    ("A system-wide hotkey: each press puts the next older entry on the clipboard", "Ein systemweites Tastenkürzel: Jeder Druck legt den nächstälteren Eintrag in die Zwischenablage"),
+    ("Launcher mode", "Launcher-Modus"),
+    ("Clicking an entry or pressing Enter in search copies it and minimizes the window", "Ein Klick auf einen Eintrag oder Enter in der Suche kopiert ihn und minimiert das Fenster"),
+    ("Minimize when idle for:", "Minimieren nach Untätigkeit von:"),
+    ("(0 = never)", "(0 = nie)"),
+    ("capture clipboard changes", "Änderungen der Zwischenablage aufzeichnen"),
+    ("to capture from", "zum Aufzeichnen"),
+    ("to set when copying", "beim Kopieren setzen"),
+    ("header", "Kopfzeile"),
+    ("dock", "andocken"),
+    (" px wide", " px breit"),
+    ("Ignore clipboard contents from before startup", "Inhalt der Zwischenablage von vor dem Start ignorieren"),
+    ("Hide previews until hovered or selected", "Vorschauen verbergen, bis sie überfahren oder ausgewählt werden"),
+    ("Always hide favorite previews", "Vorschauen von Favoriten immer verbergen"),
+    ("Wrap long previews onto two lines", "Lange Vorschauen auf zwei Zeilen umbrechen"),
+    ("Shade rows by age", "Zeilen nach Alter einfärben"),
+    ("A strip beside each row fades from bright (just copied) to faint (a week or older)", "Ein Streifen neben jeder Zeile verblasst von hell (gerade kopiert) bis schwach (eine Woche oder älter)"),
+    ("Archive history above:", "Verlauf archivieren ab:"),
+    ("Keep a backup of the previous history file", "Sicherung der vorherigen Verlaufsdatei behalten"),
+    ("Refuse to load history other users can read", "Verlauf nicht laden, wenn andere Benutzer ihn lesen können"),
+    ("Minimum entry length:", "Minimale Eintragslänge:"),
+    (" chars", " Zeichen"),
+    ("Ignore surrounding whitespace when detecting duplicates", "Umgebende Leerzeichen beim Erkennen von Duplikaten ignorieren"),
+    ("Don't capture text copied within CopyCat", "In CopyCat kopierten Text nicht aufzeichnen"),
+    ("E.g. part of an entry copied from the detail view, or text cut from the search box", "Z. B. ein Teil eines Eintrags aus der Detailansicht oder aus dem Suchfeld ausgeschnittener Text"),
+    ("Strip ANSI escape codes from captured text", "ANSI-Escape-Codes aus aufgezeichnetem Text entfernen"),
+    ("Removes terminal colors and cursor codes. \"Copy as\" can strip them from a single entry instead.", "Entfernt Terminalfarben und Cursor-Codes. \"Kopieren als\" kann sie stattdessen aus einem einzelnen Eintrag entfernen."),
+    ("Strip control characters from captured text", "Steuerzeichen aus aufgezeichnetem Text entfernen"),
+    ("Removes NUL bytes and other invisible control characters; tabs and line breaks are kept", "Entfernt NUL-Bytes und andere unsichtbare Steuerzeichen; Tabulatoren und Zeilenumbrüche bleiben erhalten"),
+    ("Log when the clipboard is cleared", "Protokollieren, wenn die Zwischenablage geleert wird"),
+    ("Capture once unchanged for:", "Aufzeichnen, wenn unverändert seit:"),
+    ("Avoids capturing partial content from apps that fill the clipboard in steps", "Verhindert unvollständige Aufzeichnungen von Apps, die die Zwischenablage schrittweise füllen"),
+    ("Ignore similar captures for:", "Ähnliche Aufzeichnungen ignorieren für:"),
+    ("(0 = off)", "(0 = aus)"),
+    ("Drops clipboard updates that only extend or shorten the previous capture, e.g. while dragging a selection", "Verwirft Änderungen, die die vorherige Aufzeichnung nur verlängern oder kürzen, z. B. beim Ziehen einer Auswahl"),
+    ("Maximum entry size:", "Maximale Eintragsgröße:"),
+    ("(0 = no limit)", "(0 = unbegrenzt)"),
+    ("Maximum history size:", "Maximale Verlaufsgröße:"),
+    ("Oldest entries are dropped past this size; favorites and locked entries are kept", "Darüber werden die ältesten Einträge verworfen; Favoriten und gesperrte Einträge bleiben erhalten"),
+    ("Watch patterns (one regex per line):", "Überwachungsmuster (ein regulärer Ausdruck pro Zeile):"),
+    ("e.g. ^\\d{6}$", "z. B. ^\\d{6}$"),
+    ("Alert", "Hinweis"),
+    ("Don't store content matching these patterns:", "Inhalte, die auf diese Muster passen, nicht speichern:"),
+    ("Restore default patterns", "Standardmuster wiederherstellen"),
+    ("\"Copy wrapped\" presets (prefix, suffix):", "Vorlagen für \"Umschlossen kopieren\" (Präfix, Suffix):"),
+    ("Remove preset", "Vorlage entfernen"),
+    ("Add preset", "Vorlage hinzufügen"),
+    ("Tab width for indentation conversions:", "Tabulatorbreite für Einrückungsumwandlungen:"),
+    ("\"Copy as list\" line:", "Zeile für \"Als Liste kopieren\":"),
+    ("{n} is the position, {time} the capture time and {text} the entry", "{n} ist die Position, {time} die Aufzeichnungszeit und {text} der Eintrag"),
+    ("Tracking parameters removed by \"Copy clean URL\" (comma-separated, * = prefix):", "Von \"Bereinigte URL kopieren\" entfernte Tracking-Parameter (kommagetrennt, * = Präfix):"),
+    ("Keyboard shortcuts (e.g. Ctrl+Shift+P; empty = none):", "Tastenkürzel (z. B. Ctrl+Shift+P; leer = keins):"),
+    ("Restore default shortcuts", "Standard-Tastenkürzel wiederherstellen"),
+    ("Right-clicking the list outside an entry offers:", "Rechtsklick in die Liste außerhalb eines Eintrags bietet:"),
+    ("Share new entries with peers on the LAN", "Neue Einträge mit Geräten im LAN teilen"),
+    ("Entries are sent unencrypted. Only enable on networks you trust.", "Einträge werden unverschlüsselt gesendet. Nur in vertrauenswürdigen Netzwerken aktivieren."),
+    ("Listen port:", "Port:"),
+    ("Shared token:", "Gemeinsames Token:"),
+    ("Peers (host:port, one per line):", "Geräte (Host:Port, eins pro Zeile):"),
+    ("Invalid pattern:", "Ungültiges Muster:"),
+    ("Clears are not added to the history; they are appended to {}", "Leerungen werden nicht in den Verlauf aufgenommen, sondern an {} angehängt"),
+    ("(0 = no limit, now {})", "(0 = unbegrenzt, derzeit {})"),
+    ("Always", "Immer"),
+    ("Only while focused", "Nur im Vordergrund"),
+    ("Only while in the background", "Nur im Hintergrund"),
+    ("Clipboard", "Zwischenablage"),
+    ("Primary selection", "Primäre Auswahl"),
+    ("Both", "Beide"),
+    ("Single click", "Einfacher Klick"),
+    ("Double click", "Doppelklick"),
+    ("No click", "Kein Klick"),
+    ("Toast", "Meldung"),
+    ("Flash window", "Fenster blinken lassen"),
+    ("Toast and flash", "Meldung und Blinken"),
+    ("Full", "Vollständig"),
+    ("Compact", "Kompakt"),
+    ("Hidden", "Ausgeblendet"),
+    ("Not docked", "Nicht angedockt"),
+    ("Left edge", "Linker Rand"),
+    ("Right edge", "Rechter Rand"),
+    ("Focus search", "Suche fokussieren"),
+    ("Toggle favorites filter", "Favoritenfilter umschalten"),
+    ("Copy newest entry", "Neuesten Eintrag kopieren"),
+    ("Open settings", "Einstellungen öffnen"),
+    ("Command palette", "Befehlspalette"),
+    ("Show only favorites", "Nur Favoriten zeigen"),
+    ("Show everything", "Alles zeigen"),
+    ("Capture clipboard now", "Zwischenablage jetzt aufzeichnen"),
+    ("Toggle favorites only", "Nur Favoriten umschalten"),
+    ("Pause/resume capture", "Aufzeichnung pausieren/fortsetzen"),
+    ("Toggle dark/light theme", "Helles/dunkles Design umschalten"),
+    ("Clear non-favorites", "Nicht-Favoriten löschen"),
+    ("Clear all", "Alles löschen"),
+    ("Copy shown entries as numbered list", "Angezeigte Einträge als nummerierte Liste kopieren"),
+    ("Show clipboard formats (debugging)", "Formate der Zwischenablage anzeigen (Debugging)"),
+    ("Export favorites to data folder", "Favoriten in den Datenordner exportieren"),
+    // Notifications
+    ("Skipped {} unreadable entries; the original file is kept as {}", "{} unlesbare Einträge übersprungen; die Originaldatei bleibt als {} erhalten"),
+    ("History not loaded or saved: the file is readable by other users (chmod 600 it and restart)", "Verlauf weder geladen noch gespeichert: Die Datei ist für andere Benutzer lesbar (mit chmod 600 schützen und neu starten)"),
+    ("Kept the unreadable file as {}", "Unlesbare Datei als {} aufbewahrt"),
+    ("Couldn't move the unreadable file aside: {}", "Unlesbare Datei konnte nicht verschoben werden: {}"),
+    ("History archived to {}", "Verlauf archiviert in {}"),
+    ("Exported {} favorites", "{} Favoriten exportiert"),
+    ("Export failed: {}", "Export fehlgeschlagen: {}"),
+    ("Import failed: {}", "Import fehlgeschlagen: {}"),
+    ("Imported {} entries; skipped {} blocked, too short or too large", "{} Einträge importiert; {} gesperrte, zu kurze oder zu große übersprungen"),
+    ("Imported {} entries and marked {} existing ones as favorite", "{} Einträge importiert und {} vorhandene als Favorit markiert"),
+    ("Imported {} entries", "{} Einträge importiert"),
+    ("Still memory only: couldn't read {}", "Weiterhin nur im Speicher: {} konnte nicht gelesen werden"),
+    ("Sync unavailable: {}", "Synchronisierung nicht verfügbar: {}"),
+    ("LAN relay not started: {}", "LAN-Weiterleitung nicht gestartet: {}"),
+    ("The primary selection isn't available on this system", "Die primäre Auswahl ist auf diesem System nicht verfügbar"),
+    ("Skipped {} clip (limit {})", "Eintrag mit {} übersprungen (Grenze {})"),
+    ("Captured content matching a watch pattern", "Inhalt aufgezeichnet, der zu einem Überwachungsmuster passt"),
+    ("No entry with id {}", "Kein Eintrag mit der ID {}"),
+    ("No entries older than {}", "Keine Einträge älter als {}"),
+    ("Nothing to capture: {}", "Nichts aufzuzeichnen: {}"),
+    ("Nothing shown to copy", "Keine angezeigten Einträge zum Kopieren"),
+    ("Copied the first {} of {} entries as a list", "Die ersten {} von {} Einträgen als Liste kopiert"),
+    ("Copied {} entries as a list", "{} Einträge als Liste kopiert"),
+    ("Deleted {} entries", "{} Einträge gelöscht"),
+    ("Capture resumed", "Aufzeichnung fortgesetzt"),
+    ("Marked {} entries as favorite", "{} Einträge als Favorit markiert"),
+    ("All shown entries are locked", "Alle angezeigten Einträge sind gesperrt"),
+    ("Copied - paste it into the other app", "Kopiert – in die andere App einfügen"),
+    ("Couldn't open {}", "{} konnte nicht geöffnet werden"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn tr_format_fills_placeholders_in_order() {
+        assert_eq!(tr_format("Imported {} entries", &[&3]), "Imported 3 entries");
+        assert_eq!(tr_format("Copied the first {} of {} entries as a list", &[&10, &12]),
+            "Copied the first 10 of 12 entries as a list");
+        // Missing arguments leave the placeholder empty rather than panicking
+        assert_eq!(tr_format("Export failed: {}", &[]), "Export failed: ");
+    }
+
+    #[test]
+    fn translations_keep_their_placeholders() {
+        let mut seen = HashSet::new();
+        for (english, german) in GERMAN_STRINGS {
+            assert!(seen.insert(english), "{:?} is translated twice", english);
+            assert_eq!(english.matches("{}").count(), german.matches("{}").count(), "{:?}", english);
+        }
+    }
+
+    /// The source string literal `literal` as the string it denotes.
+    fn unescape(literal: &str) -> String {
+        let mut result = String::new();
+        let mut chars = literal.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('n') => result.push('\n'),
+                    Some('t') => result.push('\t'),
+                    Some(other) => result.push(other),
+                    None => {}
+                },
+                c => result.push(c),
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn every_translated_string_has_a_german_entry() {
+        let catalog = Language::German.catalog().unwrap();
+        let call = regex::Regex::new(r#"\btr(?:_format)?\("((?:[^"\\]|\\.)*)""#).unwrap();
+        let mut keys: Vec<String> = call.captures_iter(include_str!("main.rs"))
+            .map(|captures| unescape(&captures[1]))
+            .collect();
+        // Labels are passed to tr() at the call site rather than as literals
+        keys.extend(crate::TimeRange::ALL.iter().map(|range| range.label().to_string()));
+        keys.extend(crate::palette::Command::ALL.iter().map(|command| command.label().to_string()));
+        keys.extend(crate::keybindings::Shortcut::ALL.iter().map(|shortcut| shortcut.label().to_string()));
+        keys.extend(crate::transforms::Transform::ALL.iter().map(|transform| transform.label().to_string()));
+        keys.extend(crate::content_type::ContentType::ALL.iter().map(|kind| kind.label().to_string()));
+        keys.extend(crate::settings::PollScope::ALL.iter().map(|scope| scope.label().to_string()));
+        keys.extend(crate::settings::Selection::ALL.iter().map(|selection| selection.label().to_string()));
+        keys.extend(crate::settings::ClickToCopy::ALL.iter().map(|click| click.label().to_string()));
+        keys.extend(crate::settings::WatchAlert::ALL.iter().map(|alert| alert.label().to_string()));
+        keys.extend(crate::settings::HeaderStyle::ALL.iter().map(|style| style.label().to_string()));
+        keys.extend(crate::settings::DockEdge::ALL.iter().map(|edge| edge.label().to_string()));
+        keys.extend(crate::settings::SortOrder::ALL.iter().map(|order| order.label().to_string()));
+        keys.extend(crate::convert::conversions("31").into_iter().map(|(label, _)| label.to_string()));
+        keys.extend(crate::convert::conversions("#ff8800").into_iter().map(|(label, _)| label.to_string()));
+
+        assert!(keys.len() > 100, "only {} keys found", keys.len());
+        let missing: Vec<&String> = keys.iter().filter(|key| !catalog.contains_key(key.as_str())).collect();
+        assert!(missing.is_empty(), "no German entry for {:?}", missing);
+    }
+}
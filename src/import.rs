@@ -0,0 +1,135 @@
+//! Importers for history exported from other clipboard managers.
+//!
+//! Two formats are understood:
+//! - a JSON array of items, as written by CopyQ scripts (`text/plain` key) and most
+//!   other tools (`text` or `content` key), with optional time and pinned/favorite flags;
+//! - tab-separated lines of `timestamp<TAB>text`, with `\n`, `\t` and `\\` escaped.
+
+use crate::ClipboardEntry;
+use serde_json::Value;
+
+/// Parse an export, detecting its format. Items without text are skipped.
+pub fn parse(content: &str) -> Result<Vec<ClipboardEntry>, String> {
+    if content.trim_start().starts_with('[') {
+        parse_json(content)
+    } else {
+        parse_tsv(content)
+    }
+}
+
+fn parse_json(content: &str) -> Result<Vec<ClipboardEntry>, String> {
+    let items: Vec<Value> = serde_json::from_str(content).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    Ok(items.iter()
+        .filter_map(|item| {
+            let text = ["text/plain", "text", "content"].iter()
+                .find_map(|key| item.get(key).and_then(Value::as_str))?;
+            let timestamp = ["timestamp", "time", "date"].iter()
+                .find_map(|key| item.get(key).and_then(Value::as_u64));
+            let favorite = ["favorite", "pinned"].iter()
+                .any(|key| item.get(key).and_then(Value::as_bool).unwrap_or(false));
+            Some(entry(text.to_string(), timestamp, favorite))
+        })
+        .collect())
+}
+
+fn parse_tsv(content: &str) -> Result<Vec<ClipboardEntry>, String> {
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            let (timestamp, text) = line.split_once('\t')
+                .ok_or_else(|| format!("Line {}: expected timestamp<TAB>text", number + 1))?;
+            let timestamp = timestamp.trim().parse::<u64>()
+                .map_err(|_| format!("Line {}: invalid timestamp", number + 1))?;
+            Ok(entry(unescape(text), Some(timestamp), false))
+        })
+        .collect()
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Build an entry, reading 13-digit timestamps as milliseconds and shorter ones as seconds.
+fn entry(content: String, timestamp: Option<u64>, favorite: bool) -> ClipboardEntry {
+    let mut entry = ClipboardEntry::new(content);
+    if let Some(timestamp) = timestamp {
+        entry.timestamp = if timestamp >= 1_000_000_000_000 { timestamp } else { timestamp * 1000 };
+        entry.id = entry.timestamp;
+    }
+    if favorite {
+        entry.set_favorite(true);
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COPYQ_EXPORT: &str = r#"[
+        {"text/plain": "from copyq", "time": 1700000000, "pinned": true},
+        {"text/plain": "second"},
+        {"image/png": "iVBORw0KGgo="}
+    ]"#;
+
+    const GENERIC_EXPORT: &str = r#"[
+        {"content": "content key", "timestamp": 1700000000123, "favorite": false},
+        {"text": "text key", "date": 1700000001}
+    ]"#;
+
+    const TSV_EXPORT: &str = "1700000000\tfirst line\\nsecond line\n\n1700000002123\ttab\\there \\\\ backslash\n";
+
+    #[test]
+    fn parses_copyq_json() {
+        let entries = parse(COPYQ_EXPORT).unwrap();
+        assert_eq!(entries.len(), 2, "the item without text is skipped");
+        assert_eq!(entries[0].content, "from copyq");
+        assert_eq!(entries[0].timestamp, 1_700_000_000_000);
+        assert!(entries[0].favorite);
+        assert_eq!(entries[1].content, "second");
+        assert!(!entries[1].favorite);
+    }
+
+    #[test]
+    fn parses_generic_json() {
+        let entries = parse(GENERIC_EXPORT).unwrap();
+        assert_eq!(entries[0].content, "content key");
+        assert_eq!(entries[0].timestamp, 1_700_000_000_123);
+        assert_eq!(entries[1].content, "text key");
+        assert_eq!(entries[1].timestamp, 1_700_000_001_000);
+    }
+
+    #[test]
+    fn parses_tab_separated_lines() {
+        let entries = parse(TSV_EXPORT).unwrap();
+        assert_eq!(entries.len(), 2, "blank lines are skipped");
+        assert_eq!(entries[0].content, "first line\nsecond line");
+        assert_eq!(entries[0].timestamp, 1_700_000_000_000);
+        assert_eq!(entries[1].content, "tab\there \\ backslash");
+        assert_eq!(entries[1].timestamp, 1_700_000_002_123);
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        assert!(parse("[{\"text\": ").err().is_some_and(|e| e.starts_with("Invalid JSON")));
+        assert_eq!(parse("1700000000\tok\nno tab here").err().as_deref(), Some("Line 2: expected timestamp<TAB>text"));
+        assert_eq!(parse("yesterday\ttext").err().as_deref(), Some("Line 1: invalid timestamp"));
+    }
+}
@@ -0,0 +1,122 @@
+//! Keeping to one running CopyCat per user and working directory.
+//!
+//! Two instances would both capture every copy and overwrite each other's saves
+//! of the same history file. The first instance listens on a localhost port
+//! derived from the user and working directory (where the settings and history
+//! live); a second launch sends its arguments there and exits, and the running
+//! instance comes to the front and handles them.
+//!
+//! Only processes on this machine can connect. A request can do no more than
+//! raise the window and select an entry, so it carries no token.
+
+use eframe::egui::Context;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use std::time::Duration;
+
+/// Sent back to a second launch, so it can tell CopyCat from another program
+/// that happens to use the port.
+const GREETING: &str = "CopyCat";
+const MAX_REQUEST_BYTES: u64 = 64 * 1024;
+const TIMEOUT: Duration = Duration::from_secs(2);
+/// Ports are picked from the dynamic range.
+const FIRST_PORT: u16 = 49152;
+const PORT_COUNT: u16 = 16384;
+
+pub enum Claim {
+    /// No other instance is running; this one should listen on the port.
+    First(TcpListener),
+    /// The arguments were handed to the running instance.
+    Forwarded,
+    /// The port is taken by something else; run without single-instance handling.
+    Unavailable,
+}
+
+/// Become the running instance, or pass `args` to the one already running.
+pub fn claim(args: &[String]) -> Claim {
+    let port = instance_port();
+    match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+        Ok(listener) => Claim::First(listener),
+        Err(_) => match forward(port, args) {
+            Ok(()) => Claim::Forwarded,
+            Err(e) => {
+                eprintln!("Port {} is in use, but not by CopyCat ({}); not checking for other instances", port, e);
+                Claim::Unavailable
+            }
+        },
+    }
+}
+
+/// FNV-1a over the user name and working directory, so the port is the same for
+/// every launch that would share a history file.
+fn instance_port() -> u16 {
+    let user = std::env::var("USER").or_else(|_| std::env::var("USERNAME")).unwrap_or_default();
+    let dir = std::env::current_dir().unwrap_or_default();
+    let key = format!("{}\0{}", user, dir.display());
+
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in key.bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    FIRST_PORT + (hash % PORT_COUNT as u32) as u16
+}
+
+fn forward(port: u16, args: &[String]) -> io::Result<()> {
+    let mut stream = TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, port).into(), TIMEOUT)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut greeting = String::new();
+    BufReader::new(&stream).take(GREETING.len() as u64 + 1).read_line(&mut greeting)?;
+    if greeting.trim_end() != GREETING {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected greeting"));
+    }
+
+    serde_json::to_writer(&mut stream, args)?;
+    stream.flush()
+}
+
+/// The running instance's end: arguments from later launches.
+pub struct Instance {
+    requests: Receiver<Vec<String>>,
+}
+
+impl Instance {
+    pub fn start(listener: TcpListener, ctx: Context) -> Self {
+        let (sender, requests) = channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let args = stream.and_then(receive);
+                match args {
+                    Ok(args) => {
+                        if sender.send(args).is_err() {
+                            return;
+                        }
+                        ctx.request_repaint();
+                    }
+                    Err(e) => eprintln!("Ignored a request from another CopyCat launch: {}", e),
+                }
+            }
+        });
+        Self { requests }
+    }
+
+    /// Arguments of every launch since the last call.
+    pub fn requests(&self) -> Vec<Vec<String>> {
+        self.requests.try_iter().collect()
+    }
+}
+
+fn receive(mut stream: TcpStream) -> io::Result<Vec<String>> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    writeln!(stream, "{}", GREETING)?;
+
+    let mut buffer = Vec::new();
+    (&stream).take(MAX_REQUEST_BYTES + 1).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 > MAX_REQUEST_BYTES {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "request too large"));
+    }
+    Ok(serde_json::from_slice(&buffer)?)
+}
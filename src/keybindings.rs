@@ -0,0 +1,181 @@
+//! User-configurable keyboard shortcuts.
+//!
+//! Bindings are stored in settings as chords like `"Ctrl+Shift+P"` and parsed into
+//! egui shortcuts when settings load or change. `Ctrl` and `Cmd` both mean the
+//! platform's command key, so the same settings file works on macOS and elsewhere.
+
+use eframe::egui::{Key, KeyboardShortcut, Modifiers};
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Shortcut {
+    FocusSearch,
+    ToggleFavorites,
+    CopyTop,
+    OpenSettings,
+    CommandPalette,
+    ShowFavorites,
+    ShowAll,
+}
+
+impl Shortcut {
+    pub const ALL: [Shortcut; 7] = [
+        Shortcut::FocusSearch,
+        Shortcut::ToggleFavorites,
+        Shortcut::CopyTop,
+        Shortcut::OpenSettings,
+        Shortcut::CommandPalette,
+        Shortcut::ShowFavorites,
+        Shortcut::ShowAll,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Shortcut::FocusSearch => "Focus search",
+            Shortcut::ToggleFavorites => "Toggle favorites filter",
+            Shortcut::CopyTop => "Copy newest entry",
+            Shortcut::OpenSettings => "Open settings",
+            Shortcut::CommandPalette => "Command palette",
+            Shortcut::ShowFavorites => "Show only favorites",
+            Shortcut::ShowAll => "Show everything",
+        }
+    }
+
+    pub fn default_chord(self) -> &'static str {
+        match self {
+            Shortcut::FocusSearch => "Ctrl+F",
+            Shortcut::ToggleFavorites => "Ctrl+Shift+F",
+            Shortcut::CopyTop => "Ctrl+Shift+V",
+            Shortcut::OpenSettings => "Ctrl+Comma",
+            Shortcut::CommandPalette => "Ctrl+Shift+P",
+            Shortcut::ShowFavorites => "Alt+F",
+            Shortcut::ShowAll => "Alt+A",
+        }
+    }
+}
+
+pub fn default_bindings() -> BTreeMap<Shortcut, String> {
+    Shortcut::ALL.iter().map(|&shortcut| (shortcut, shortcut.default_chord().to_string())).collect()
+}
+
+/// The chord bound to `shortcut`, falling back to the default for actions added
+/// after the settings file was written. An empty chord leaves the action unbound.
+pub fn chord(bindings: &BTreeMap<Shortcut, String>, shortcut: Shortcut) -> &str {
+    bindings.get(&shortcut).map(String::as_str).unwrap_or(shortcut.default_chord())
+}
+
+/// Parse a chord such as `"Ctrl+Shift+P"` or `"Alt+F2"`.
+pub fn parse_chord(chord: &str) -> Result<KeyboardShortcut, String> {
+    let mut modifiers = Modifiers::NONE;
+    let mut key = None;
+
+    for part in chord.split('+').map(str::trim) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "cmd" | "command" => modifiers = modifiers.plus(Modifiers::COMMAND),
+            "alt" | "option" => modifiers = modifiers.plus(Modifiers::ALT),
+            "shift" => modifiers = modifiers.plus(Modifiers::SHIFT),
+            _ if key.is_some() => return Err(format!("\"{}\" has more than one key", chord)),
+            _ => key = Some(Key::from_name(part).ok_or_else(|| format!("Unknown key \"{}\"", part))?),
+        }
+    }
+
+    let key = key.ok_or_else(|| format!("\"{}\" has no key", chord))?;
+    Ok(KeyboardShortcut::new(modifiers, key))
+}
+
+/// Parse every bound chord, skipping invalid ones (see `problems`). The result is
+/// ordered by modifier count, most first, so that e.g. Ctrl+Shift+F is consumed
+/// before Ctrl+F gets a chance to match it.
+pub fn compile(bindings: &BTreeMap<Shortcut, String>) -> Vec<(Shortcut, KeyboardShortcut)> {
+    let mut compiled: Vec<(Shortcut, KeyboardShortcut)> = Shortcut::ALL.iter()
+        .filter(|&&shortcut| !chord(bindings, shortcut).trim().is_empty())
+        .filter_map(|&shortcut| parse_chord(chord(bindings, shortcut)).ok().map(|parsed| (shortcut, parsed)))
+        .collect();
+    compiled.sort_by_key(|(_, parsed)| std::cmp::Reverse(modifier_count(parsed.modifiers)));
+    compiled
+}
+
+fn modifier_count(modifiers: Modifiers) -> u8 {
+    modifiers.alt as u8 + modifiers.shift as u8 + (modifiers.command || modifiers.ctrl || modifiers.mac_cmd) as u8
+}
+
+/// Invalid chords and chords bound to more than one action, for display in settings.
+pub fn problems(bindings: &BTreeMap<Shortcut, String>) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut parsed: Vec<(Shortcut, KeyboardShortcut)> = Vec::new();
+
+    for shortcut in Shortcut::ALL {
+        let chord = chord(bindings, shortcut);
+        if chord.trim().is_empty() {
+            continue;
+        }
+        match parse_chord(chord) {
+            Ok(current) => {
+                if let Some((other, _)) = parsed.iter().find(|(_, existing)| *existing == current) {
+                    problems.push(format!("{} is bound to both \"{}\" and \"{}\"", chord, other.label(), shortcut.label()));
+                }
+                parsed.push((shortcut, current));
+            }
+            Err(e) => problems.push(format!("{}: {}", shortcut.label(), e)),
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modifiers_and_key() {
+        let parsed = parse_chord("Ctrl+Shift+P").unwrap();
+        assert_eq!(parsed, KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::P));
+        // Case and spacing don't matter, and Cmd is the same as Ctrl
+        assert_eq!(parse_chord(" cmd + shift + p ").unwrap(), parsed);
+        assert_eq!(parse_chord("Alt+F2").unwrap(), KeyboardShortcut::new(Modifiers::ALT, Key::F2));
+    }
+
+    #[test]
+    fn rejects_invalid_chords() {
+        assert_eq!(parse_chord("Ctrl+A+B").err().unwrap(), "\"Ctrl+A+B\" has more than one key");
+        assert_eq!(parse_chord("Ctrl+Nope").err().unwrap(), "Unknown key \"Nope\"");
+        assert_eq!(parse_chord("Ctrl+Shift").err().unwrap(), "\"Ctrl+Shift\" has no key");
+        assert!(parse_chord("").is_err());
+    }
+
+    #[test]
+    fn an_empty_chord_leaves_the_action_unbound() {
+        let mut bindings = default_bindings();
+        bindings.insert(Shortcut::FocusSearch, "  ".to_string());
+        assert!(compile(&bindings).iter().all(|(shortcut, _)| *shortcut != Shortcut::FocusSearch));
+        assert!(problems(&bindings).is_empty());
+    }
+
+    #[test]
+    fn reports_duplicate_and_invalid_bindings() {
+        assert!(problems(&default_bindings()).is_empty());
+
+        let mut bindings = default_bindings();
+        bindings.insert(Shortcut::ShowAll, "Alt+F".to_string());
+        bindings.insert(Shortcut::CopyTop, "Ctrl+Nope".to_string());
+        assert_eq!(problems(&bindings), [
+            "Copy newest entry: Unknown key \"Nope\"",
+            "Alt+F is bound to both \"Show only favorites\" and \"Show everything\"",
+        ]);
+        // The invalid chord is skipped, the duplicate still compiles
+        let compiled = compile(&bindings);
+        assert_eq!(compiled.len(), Shortcut::ALL.len() - 1);
+        assert!(compiled.iter().all(|(shortcut, _)| *shortcut != Shortcut::CopyTop));
+    }
+
+    #[test]
+    fn compile_puts_the_most_modifiers_first() {
+        let compiled = compile(&default_bindings());
+        let counts: Vec<u8> = compiled.iter().map(|(_, parsed)| modifier_count(parsed.modifiers)).collect();
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]), "{:?}", counts);
+
+        let position = |shortcut| compiled.iter().position(|(bound, _)| *bound == shortcut).unwrap();
+        assert!(position(Shortcut::ToggleFavorites) < position(Shortcut::FocusSearch));
+    }
+}
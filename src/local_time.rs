@@ -0,0 +1,68 @@
+//! The local time zone's offset from UTC, which std doesn't expose.
+//!
+//! Timestamps are stored in UTC; anything shown as a calendar date or clock time
+//! (export file names, "Today", custom date ranges) is shifted by this offset.
+
+/// Seconds to add to the UTC time `utc_secs` to get the local wall-clock time,
+/// including daylight saving time in effect at that moment. 0 where the time zone
+/// can't be determined.
+#[cfg(unix)]
+pub fn offset_secs(utc_secs: i64) -> i64 {
+    let time = utc_secs as libc::time_t;
+    // SAFETY: tm is plain data, and localtime_r only writes to the struct we pass
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    if unsafe { libc::localtime_r(&time, &mut tm) }.is_null() {
+        return 0;
+    }
+    tm.tm_gmtoff as i64
+}
+
+#[cfg(windows)]
+pub fn offset_secs(utc_secs: i64) -> i64 {
+    use windows_sys::Win32::Foundation::{FILETIME, SYSTEMTIME};
+    use windows_sys::Win32::System::Time::{FileTimeToSystemTime, SystemTimeToTzSpecificLocalTime};
+
+    // FILETIME counts 100 ns intervals since 1601-01-01
+    let ticks = (utc_secs.max(0) as u64 + 11_644_473_600) * 10_000_000;
+    let file_time = FILETIME { dwLowDateTime: ticks as u32, dwHighDateTime: (ticks >> 32) as u32 };
+    // SAFETY: SYSTEMTIME is plain data, and both calls only write to the structs we pass
+    let mut utc: SYSTEMTIME = unsafe { std::mem::zeroed() };
+    let mut local: SYSTEMTIME = unsafe { std::mem::zeroed() };
+    let converted = unsafe {
+        FileTimeToSystemTime(&file_time, &mut utc) != 0
+            && SystemTimeToTzSpecificLocalTime(std::ptr::null(), &utc, &mut local) != 0
+    };
+    if !converted {
+        return 0;
+    }
+
+    let secs = |time: &SYSTEMTIME| {
+        let days = crate::convert::days_from_civil(time.wYear as i64, time.wMonth as u32, time.wDay as u32);
+        days * 86_400 + time.wHour as i64 * 3600 + time.wMinute as i64 * 60 + time.wSecond as i64
+    };
+    secs(&local) - secs(&utc)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn offset_secs(_utc_secs: i64) -> i64 {
+    0
+}
+
+/// The local wall-clock time of `utc_secs`, as seconds since 1970-01-01 00:00 local.
+pub fn wall_clock(utc_secs: i64) -> i64 {
+    utc_secs + offset_secs(utc_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_are_within_real_time_zones() {
+        for utc_secs in [0, 1_700_000_000, 1_719_792_000] {
+            let offset = offset_secs(utc_secs);
+            assert!((-12 * 3600..=14 * 3600).contains(&offset), "{}", offset);
+            assert_eq!(wall_clock(utc_secs), utc_secs + offset);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::i18n::tr;
+use serde::{Serialize, Deserialize};
+
+/// An app-level action that can be run from the command palette or the list's
+/// background menu.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Command {
+    CaptureNow,
+    FocusSearch,
+    ToggleFavoritesFilter,
+    ToggleCapture,
+    ToggleTheme,
+    OpenSettings,
+    ClearNonFavorites,
+    ClearAll,
+    CopyShownAsList,
+    ClipboardFormats,
+    ExportFavorites,
+}
+
+impl Command {
+    pub const ALL: [Command; 11] = [
+        Command::CaptureNow,
+        Command::FocusSearch,
+        Command::ToggleFavoritesFilter,
+        Command::ToggleCapture,
+        Command::ToggleTheme,
+        Command::OpenSettings,
+        Command::ClearNonFavorites,
+        Command::ClearAll,
+        Command::CopyShownAsList,
+        Command::ClipboardFormats,
+        Command::ExportFavorites,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Command::CaptureNow => "Capture clipboard now",
+            Command::FocusSearch => "Focus search",
+            Command::ToggleFavoritesFilter => "Toggle favorites only",
+            Command::ToggleCapture => "Pause/resume capture",
+            Command::ToggleTheme => "Toggle dark/light theme",
+            Command::OpenSettings => "Open settings",
+            Command::ClearNonFavorites => "Clear non-favorites",
+            Command::ClearAll => "Clear all",
+            Command::CopyShownAsList => "Copy shown entries as numbered list",
+            Command::ClipboardFormats => "Show clipboard formats (debugging)",
+            Command::ExportFavorites => "Export favorites to data folder",
+        }
+    }
+}
+
+/// Score how well `query` fuzzily matches `candidate`, or `None` if it doesn't.
+///
+/// Every query character must appear in order in the candidate (case-insensitive).
+/// Consecutive matches and matches at word starts score higher.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let offset = candidate[position..].iter().position(|&c| c == query_char)?;
+        let index = position + offset;
+
+        score += 1;
+        if previous_match.is_some_and(|prev| prev + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !candidate[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Commands matching `query` in their shown (translated) label, best match first.
+pub fn matching_commands(query: &str) -> Vec<Command> {
+    let mut matches: Vec<(i32, Command)> = Command::ALL.iter()
+        .filter_map(|&command| fuzzy_score(query, tr(command.label())).map(|score| (score, command)))
+        .collect();
+    // Stable sort keeps the declaration order for equal scores
+    matches.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    matches.into_iter().map(|(_, command)| command).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_characters_must_appear_in_order() {
+        assert!(fuzzy_score("abc", "a_b_c").is_some());
+        assert!(fuzzy_score("ABC", "xaxbxc").is_some());
+        assert!(fuzzy_score("cba", "abc").is_none());
+        assert!(fuzzy_score("abcd", "abc").is_none());
+        // Spaces in the query are ignored
+        assert_eq!(fuzzy_score("c s", "xcxs"), fuzzy_score("cs", "xcxs"));
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher() {
+        assert_eq!(fuzzy_score("ab", "xab"), Some(7));
+        assert_eq!(fuzzy_score("ab", "xaxb"), Some(2));
+    }
+
+    #[test]
+    fn word_starts_score_higher() {
+        assert_eq!(fuzzy_score("c", "xc"), Some(1));
+        assert_eq!(fuzzy_score("c", "x c"), Some(4));
+        assert_eq!(fuzzy_score("c", "c"), Some(4));
+    }
+
+    #[test]
+    fn equal_scores_keep_the_declaration_order() {
+        assert!(matching_commands("") == Command::ALL);
+
+        let matches = matching_commands("clear");
+        assert!(matches[..2] == [Command::ClearNonFavorites, Command::ClearAll]);
+        assert!(fuzzy_score("clear", Command::ClearNonFavorites.label()) == fuzzy_score("clear", Command::ClearAll.label()));
+    }
+}
@@ -0,0 +1,153 @@
+//! Opt-in clipboard relay between the user's own machines on a LAN.
+//!
+//! Threat model: the relay is meant for a trusted home or office network and is
+//! off by default.
+//! - Messages are plain JSON over TCP. Anyone who can observe the network can read
+//!   relayed clipboard content, including the shared token.
+//! - The shared token only stops casual injection by other hosts on the LAN. A peer
+//!   with the token can insert arbitrary entries into history; it can never read
+//!   history or write to the OS clipboard, since received entries are only stored.
+//! - The listener binds on all interfaces on the configured port. Do not enable it on
+//!   untrusted networks or forward the port to the internet.
+//! - Messages larger than `MAX_MESSAGE_BYTES` or slower than `READ_TIMEOUT` are
+//!   dropped, so a misbehaving peer cannot exhaust memory or stall the listener.
+
+use serde::{Serialize, Deserialize};
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const MAX_MESSAGE_BYTES: u64 = 10 * 1024 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Serialize, Deserialize)]
+struct RelayMessage {
+    token: String,
+    content: String,
+}
+
+/// A running relay: listens for entries from peers and sends ours to them.
+pub struct Relay {
+    received: Receiver<String>,
+    token: Arc<Mutex<String>>,
+    peers: Vec<String>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Relay {
+    pub fn start(port: u16, token: String, peers: Vec<String>) -> io::Result<Self> {
+        if token.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "a shared token is required"));
+        }
+
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        listener.set_nonblocking(true)?;
+
+        let (sender, received) = channel();
+        let token = Arc::new(Mutex::new(token));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let listener_token = Arc::clone(&token);
+        let listener_stop = Arc::clone(&stop);
+        thread::spawn(move || listen(listener, listener_token, sender, listener_stop));
+
+        Ok(Self { received, token, peers, stop })
+    }
+
+    /// Update the token and peer list without rebinding the listener.
+    pub fn configure(&mut self, token: String, peers: Vec<String>) {
+        if let Ok(mut current) = self.token.lock() {
+            *current = token;
+        }
+        self.peers = peers;
+    }
+
+    /// Entries received from peers since the last call.
+    pub fn received(&self) -> Vec<String> {
+        self.received.try_iter().collect()
+    }
+
+    /// Send a newly captured entry to every configured peer, in the background.
+    pub fn broadcast(&self, content: &str) {
+        let token = match self.token.lock() {
+            Ok(token) => token.clone(),
+            Err(_) => return,
+        };
+        let Ok(message) = serde_json::to_vec(&RelayMessage { token, content: content.to_string() }) else {
+            return;
+        };
+        let message = Arc::new(message);
+
+        for peer in self.peers.clone() {
+            let message = Arc::clone(&message);
+            thread::spawn(move || {
+                if let Err(e) = send(&peer, &message) {
+                    eprintln!("Failed to relay entry to {}: {}", peer, e);
+                }
+            });
+        }
+    }
+}
+
+impl Drop for Relay {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn listen(listener: TcpListener, token: Arc<Mutex<String>>, sender: Sender<String>, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, address)) => match receive(stream, &token) {
+                Ok(content) => {
+                    if sender.send(content).is_err() {
+                        return;
+                    }
+                }
+                Err(e) => eprintln!("Rejected relay message from {}: {}", address, e),
+            },
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => thread::sleep(ACCEPT_INTERVAL),
+            Err(e) => eprintln!("Relay listener error: {}", e),
+        }
+    }
+}
+
+fn receive(stream: TcpStream, token: &Mutex<String>) -> io::Result<String> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+
+    let mut buffer = Vec::new();
+    stream.take(MAX_MESSAGE_BYTES + 1).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 > MAX_MESSAGE_BYTES {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "message too large"));
+    }
+
+    let message: RelayMessage = serde_json::from_slice(&buffer)?;
+    let expected = token.lock().map(|token| token.clone()).unwrap_or_default();
+    if !tokens_match(&message.token, &expected) {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "invalid token"));
+    }
+
+    Ok(message.content)
+}
+
+fn send(peer: &str, message: &[u8]) -> io::Result<()> {
+    let address = peer.to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "could not resolve peer"))?;
+    let mut stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+    stream.write_all(message)
+}
+
+/// Compare tokens in time independent of where they differ.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    !expected.is_empty()
+        && given.len() == expected.len()
+        && given.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
@@ -0,0 +1,411 @@
+use crate::content_type::ContentType;
+use crate::i18n::Language;
+use crate::keybindings::{self, Shortcut};
+use crate::palette::Command;
+use crate::transforms::DEFAULT_TRACKING_PARAMS;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::fs;
+
+/// User preferences, persisted as JSON next to the history file.
+///
+/// Every field has a default so that settings files written by older
+/// versions keep loading after new options are added.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    /// Location of the history file.
+    pub history_path: String,
+    /// Merge in changes other machines make to the history file (e.g. in a synced folder).
+    pub sync_enabled: bool,
+    /// Keep history in memory only; nothing is read from or written to the history file.
+    pub ephemeral: bool,
+    /// Restore the previous clipboard text after a transform-copy.
+    pub restore_after_transform: bool,
+    /// How long the transformed text stays on the clipboard before restoring.
+    pub restore_delay_ms: u64,
+    /// How long "Copy with auto-clear" leaves an entry on the clipboard.
+    pub auto_clear_secs: u64,
+    /// Regular expressions that trigger an alert when newly captured content matches.
+    pub watch_patterns: Vec<String>,
+    /// How to alert when a watch pattern matches.
+    pub watch_alert: WatchAlert,
+    /// Skip captures shorter than this many characters (after trimming).
+    pub min_entry_chars: usize,
+    /// Skip captures larger than this many bytes (0 disables the limit).
+    pub max_entry_bytes: usize,
+    /// Once entry content takes more than this many bytes in total, the oldest
+    /// non-favorite entries are dropped (0 disables the budget).
+    pub max_history_bytes: usize,
+    /// After a capture, ignore content from the same selection that extends or
+    /// shortens it for this many milliseconds (0 disables).
+    pub capture_cooldown_ms: u64,
+    /// Only capture content that stayed unchanged on the clipboard for this long (0 disables).
+    pub capture_stabilize_ms: u64,
+    /// Treat content differing only in leading/trailing whitespace as a duplicate.
+    pub dedup_trim: bool,
+    /// Don't capture text copied from CopyCat's own search box, detail view and
+    /// other text widgets.
+    pub ignore_own_copies: bool,
+    /// Remove ANSI escape codes from captured text. Off by default since some
+    /// users copy escape sequences on purpose.
+    pub strip_ansi_on_capture: bool,
+    /// Remove NULs and other control characters (except tabs and line breaks) from
+    /// captured text. Off by default so binary-ish content is kept as copied.
+    pub strip_control_on_capture: bool,
+    /// Record when the clipboard is emptied in a separate log next to the history
+    /// file, for tracking down apps that wipe it. The history itself is unaffected.
+    pub log_clear_events: bool,
+    /// Exchange new entries with peers on the LAN. See `relay` for the threat model.
+    pub relay_enabled: bool,
+    pub relay_port: u16,
+    /// Shared secret peers must present; the relay refuses to start without one.
+    pub relay_token: String,
+    /// Peer addresses as `host:port`.
+    pub relay_peers: Vec<String>,
+    /// Hide entry previews until hovered or selected.
+    pub mask_previews: bool,
+    /// Always hide favorite previews, even when `mask_previews` is off.
+    pub mask_favorites: bool,
+    /// Wrap long previews onto a second line instead of cutting them at one short line.
+    pub wrap_previews: bool,
+    /// Mark each row with a strip that fades from bright to faint as the entry ages.
+    pub age_tint: bool,
+    /// Archive the history file once it grows past this many megabytes (0 disables).
+    pub rotate_at_mb: u64,
+    /// Keep the previous history file as `.bak` when saving, for recovery if the main file gets corrupted.
+    pub keep_backup: bool,
+    /// Refuse to load a history file that other users can read. Only checked on Unix.
+    pub strict_permissions: bool,
+    /// Don't capture whatever is already on the clipboard when CopyCat starts.
+    pub ignore_clipboard_at_startup: bool,
+    pub sort_order: SortOrder,
+    /// List favorites above everything else, each group in `sort_order`.
+    pub favorites_first: bool,
+    /// Only list entries of this type (`None` lists everything).
+    pub type_filter: Option<ContentType>,
+    pub header: HeaderStyle,
+    /// UI language; strings without a translation stay in English.
+    pub language: Language,
+    /// Keep the window against a screen edge at `dock_width`, full monitor height.
+    pub dock: DockEdge,
+    pub dock_width: f32,
+    /// Refuse to store content matching `blocklist_patterns`.
+    pub blocklist_enabled: bool,
+    pub blocklist_patterns: Vec<String>,
+    /// Query parameters removed by "Copy clean URL"; a trailing `*` matches a prefix.
+    pub tracking_params: Vec<String>,
+    /// Prefix/suffix pairs offered by "Copy wrapped".
+    pub wrap_presets: Vec<WrapPreset>,
+    /// Suggested name for exports; see `convert::expand_time_template`. `{ext}` is
+    /// the format's extension.
+    pub export_filename_template: String,
+    /// Columns per tab for the tabs/spaces conversions in "Copy as".
+    pub indent_width: usize,
+    /// One line of "Copy as list"; `{n}`, `{time}` and `{text}` are filled in.
+    pub list_template: String,
+    pub click_to_copy: ClickToCopy,
+    /// Selecting an entry (or pressing Enter in search) copies it and hides the window.
+    pub launcher_mode: bool,
+    /// In launcher mode, minimize the window after this many seconds without
+    /// input (0 disables).
+    pub launcher_idle_secs: u64,
+    /// Global hotkey that cycles the clipboard through recent entries (empty disables).
+    pub ring_hotkey: String,
+    /// How many of the most recent entries the clipboard ring cycles through.
+    pub ring_size: usize,
+    /// Whether to capture depending on the window's focus.
+    pub poll_scope: PollScope,
+    /// X11/Wayland selection(s) to capture from. Only used on Linux.
+    pub poll_selection: Selection,
+    /// X11/Wayland selection(s) to set when copying an entry. Only used on Linux.
+    pub copy_selection: Selection,
+    /// Chords like `"Ctrl+Shift+P"`; an empty chord leaves the action unbound.
+    pub keybindings: BTreeMap<Shortcut, String>,
+    /// Commands offered when right-clicking the list outside an entry, in order.
+    pub background_menu: Vec<Command>,
+    /// Distinct past search queries, most recent first.
+    pub recent_searches: Vec<String>,
+}
+
+/// When the clipboard is polled, relative to the CopyCat window's focus.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum PollScope {
+    Always,
+    OnlyWhenFocused,
+    OnlyWhenUnfocused,
+}
+
+impl PollScope {
+    pub const ALL: [PollScope; 3] = [PollScope::Always, PollScope::OnlyWhenFocused, PollScope::OnlyWhenUnfocused];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PollScope::Always => "Always",
+            PollScope::OnlyWhenFocused => "Only while focused",
+            PollScope::OnlyWhenUnfocused => "Only while in the background",
+        }
+    }
+
+    pub fn allows(self, focused: bool) -> bool {
+        match self {
+            PollScope::Always => true,
+            PollScope::OnlyWhenFocused => focused,
+            PollScope::OnlyWhenUnfocused => !focused,
+        }
+    }
+}
+
+/// The Linux CLIPBOARD (Ctrl+C) and PRIMARY (mouse selection) selections.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Selection {
+    Clipboard,
+    Primary,
+    Both,
+}
+
+impl Selection {
+    #[cfg(target_os = "linux")]
+    pub const ALL: [Selection; 3] = [Selection::Clipboard, Selection::Primary, Selection::Both];
+
+    #[cfg(target_os = "linux")]
+    pub fn label(self) -> &'static str {
+        match self {
+            Selection::Clipboard => "Clipboard",
+            Selection::Primary => "Primary selection",
+            Selection::Both => "Both",
+        }
+    }
+
+    /// Other platforms only have the regular clipboard.
+    pub fn includes_clipboard(self) -> bool {
+        cfg!(not(target_os = "linux")) || self != Selection::Primary
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn includes_primary(self) -> bool {
+        self != Selection::Clipboard
+    }
+}
+
+/// Which click on a history row copies it. A single click always selects.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ClickToCopy {
+    Single,
+    Double,
+    /// Clicks only select; copying is left to the copy button and context menu.
+    Never,
+}
+
+impl ClickToCopy {
+    pub const ALL: [ClickToCopy; 3] = [ClickToCopy::Single, ClickToCopy::Double, ClickToCopy::Never];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ClickToCopy::Single => "Single click",
+            ClickToCopy::Double => "Double click",
+            ClickToCopy::Never => "No click",
+        }
+    }
+}
+
+/// Patterns for common secrets that shouldn't end up in a plaintext history file.
+pub const DEFAULT_BLOCKLIST: [&str; 4] = [
+    // AWS access key id
+    r"AKIA[0-9A-Z]{16}",
+    // JSON Web Token
+    r"eyJ[A-Za-z0-9_-]+\.eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+",
+    // PEM private key
+    r"-----BEGIN [A-Z ]*PRIVATE KEY-----",
+    // 16-digit card number, optionally grouped by spaces or dashes
+    r"\b[3-6]\d{3}[ -]?\d{4}[ -]?\d{4}[ -]?\d{4}\b",
+];
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum WatchAlert {
+    Toast,
+    Flash,
+    ToastAndFlash,
+}
+
+impl WatchAlert {
+    pub const ALL: [WatchAlert; 3] = [WatchAlert::Toast, WatchAlert::Flash, WatchAlert::ToastAndFlash];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WatchAlert::Toast => "Toast",
+            WatchAlert::Flash => "Flash window",
+            WatchAlert::ToastAndFlash => "Toast and flash",
+        }
+    }
+
+    pub fn toast(self) -> bool {
+        matches!(self, WatchAlert::Toast | WatchAlert::ToastAndFlash)
+    }
+
+    pub fn flash(self) -> bool {
+        matches!(self, WatchAlert::Flash | WatchAlert::ToastAndFlash)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct WrapPreset {
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl WrapPreset {
+    pub fn label(&self) -> String {
+        format!("{}…{}", self.prefix, self.suffix)
+    }
+}
+
+/// How much room the app title takes above the search bar.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum HeaderStyle {
+    Full,
+    Compact,
+    Hidden,
+}
+
+impl HeaderStyle {
+    pub const ALL: [HeaderStyle; 3] = [HeaderStyle::Full, HeaderStyle::Compact, HeaderStyle::Hidden];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HeaderStyle::Full => "Full",
+            HeaderStyle::Compact => "Compact",
+            HeaderStyle::Hidden => "Hidden",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum DockEdge {
+    Off,
+    Left,
+    Right,
+}
+
+impl DockEdge {
+    pub const ALL: [DockEdge; 3] = [DockEdge::Off, DockEdge::Left, DockEdge::Right];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DockEdge::Off => "Not docked",
+            DockEdge::Left => "Left edge",
+            DockEdge::Right => "Right edge",
+        }
+    }
+}
+
+/// Order of the history list, applied after filtering.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    Newest,
+    Oldest,
+    MostUsed,
+    Largest,
+    /// Favorites by when they were favorited, most recent first, then everything else.
+    RecentlyFavorited,
+}
+
+impl SortOrder {
+    pub const ALL: [SortOrder; 5] = [SortOrder::Newest, SortOrder::Oldest, SortOrder::MostUsed, SortOrder::Largest, SortOrder::RecentlyFavorited];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortOrder::Newest => "Newest",
+            SortOrder::Oldest => "Oldest",
+            SortOrder::MostUsed => "Most used",
+            SortOrder::Largest => "Largest",
+            SortOrder::RecentlyFavorited => "Recently favorited",
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            history_path: "clipboard_history.json".to_string(),
+            sync_enabled: false,
+            ephemeral: false,
+            restore_after_transform: false,
+            restore_delay_ms: 5000,
+            auto_clear_secs: 30,
+            watch_patterns: Vec::new(),
+            watch_alert: WatchAlert::ToastAndFlash,
+            min_entry_chars: 1,
+            max_entry_bytes: 0,
+            max_history_bytes: 0,
+            capture_cooldown_ms: 0,
+            capture_stabilize_ms: 100,
+            dedup_trim: false,
+            ignore_own_copies: true,
+            strip_ansi_on_capture: false,
+            strip_control_on_capture: false,
+            log_clear_events: false,
+            relay_enabled: false,
+            relay_port: 47800,
+            relay_token: String::new(),
+            relay_peers: Vec::new(),
+            mask_previews: false,
+            mask_favorites: false,
+            wrap_previews: false,
+            age_tint: false,
+            rotate_at_mb: 0,
+            keep_backup: true,
+            strict_permissions: false,
+            ignore_clipboard_at_startup: false,
+            sort_order: SortOrder::Newest,
+            favorites_first: false,
+            type_filter: None,
+            header: HeaderStyle::Full,
+            language: Language::English,
+            dock: DockEdge::Off,
+            dock_width: 420.0,
+            blocklist_enabled: true,
+            blocklist_patterns: DEFAULT_BLOCKLIST.iter().map(|pattern| pattern.to_string()).collect(),
+            tracking_params: DEFAULT_TRACKING_PARAMS.iter().map(|param| param.to_string()).collect(),
+            wrap_presets: [("\"", "\""), ("'", "'"), ("`", "`"), ("(", ")"), ("- ", "")].iter()
+                .map(|&(prefix, suffix)| WrapPreset { prefix: prefix.to_string(), suffix: suffix.to_string() })
+                .collect(),
+            export_filename_template: "copycat-history-{YYYYMMDD-HHMMSS}.{ext}".to_string(),
+            indent_width: 4,
+            list_template: "{n}. {text}".to_string(),
+            click_to_copy: ClickToCopy::Single,
+            launcher_mode: false,
+            launcher_idle_secs: 0,
+            ring_hotkey: String::new(),
+            ring_size: 10,
+            poll_scope: PollScope::Always,
+            poll_selection: Selection::Clipboard,
+            copy_selection: Selection::Clipboard,
+            keybindings: keybindings::default_bindings(),
+            background_menu: vec![Command::CaptureNow, Command::ClearNonFavorites, Command::ExportFavorites, Command::OpenSettings],
+            recent_searches: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Load settings from disk, falling back to defaults if the file is missing or invalid.
+    pub fn load(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                eprintln!("Failed to parse settings, using defaults: {}", e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save settings to disk.
+    pub fn save(&self, path: &Path) {
+        if let Ok(json) = serde_json::to_string_pretty(self)
+            && let Err(e) = crate::write_atomically(path, &json) {
+            eprintln!("Failed to write settings file: {}", e);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Finding entries that are nearly, but not exactly, the same.
+
+/// Entries longer than this are skipped; edit distance is quadratic in length.
+const MAX_CHARS: usize = 2000;
+/// Stop after this many pairwise comparisons so huge histories stay responsive.
+const MAX_COMPARISONS: usize = 500_000;
+
+/// Group entries whose normalized Levenshtein similarity is at least `threshold`
+/// (0.0-1.0). Returns clusters of two or more ids, largest first.
+///
+/// Two strings can only be that similar if their lengths differ by at most
+/// `(1 - threshold) * longer`, so entries are sorted by length and each one is
+/// only compared with the following entries in that window.
+pub fn clusters(entries: &[(u64, &str)], threshold: f64) -> Vec<Vec<u64>> {
+    let mut sized: Vec<(usize, u64, &str)> = entries.iter()
+        .map(|&(id, content)| (content.chars().count(), id, content))
+        .filter(|&(len, _, _)| len > 0 && len <= MAX_CHARS)
+        .collect();
+    sized.sort_by_key(|&(len, _, _)| len);
+
+    let mut parent: Vec<usize> = (0..sized.len()).collect();
+    let mut comparisons = 0;
+    'outer: for i in 0..sized.len() {
+        for j in i + 1..sized.len() {
+            let (len_a, _, a) = sized[i];
+            let (len_b, _, b) = sized[j];
+            if (len_b - len_a) as f64 > (1.0 - threshold) * len_b as f64 {
+                break;
+            }
+            comparisons += 1;
+            if comparisons > MAX_COMPARISONS {
+                break 'outer;
+            }
+            if a != b && strsim::normalized_levenshtein(a, b) >= threshold {
+                let (root_a, root_b) = (find(&mut parent, i), find(&mut parent, j));
+                parent[root_a] = root_b;
+            }
+        }
+    }
+
+    let mut groups: std::collections::HashMap<usize, Vec<u64>> = std::collections::HashMap::new();
+    for (i, &(_, id, _)) in sized.iter().enumerate() {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(id);
+    }
+    let mut clusters: Vec<Vec<u64>> = groups.into_values().filter(|group| group.len() > 1).collect();
+    clusters.sort_by_key(|group| std::cmp::Reverse(group.len()));
+    clusters
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Clusters with their ids sorted, for comparison.
+    fn sorted(mut clusters: Vec<Vec<u64>>) -> Vec<Vec<u64>> {
+        for cluster in &mut clusters {
+            cluster.sort();
+        }
+        clusters
+    }
+
+    #[test]
+    fn groups_only_entries_over_the_threshold() {
+        let entries = [(1, "abcdefghij"), (2, "abcdefghiX"), (3, "something else")];
+        assert_eq!(sorted(clusters(&entries, 0.9)), [[1, 2]]);
+        assert!(clusters(&entries, 0.95).is_empty());
+    }
+
+    #[test]
+    fn similarity_groups_transitively() {
+        // 1 and 3 are only 80% alike, but both are 90% alike with 2
+        let entries = [(1, "abcdefghij"), (2, "abcdefghiX"), (3, "abcdefghYX")];
+        assert_eq!(sorted(clusters(&entries, 0.9)), [[1, 2, 3]]);
+    }
+
+    #[test]
+    fn singletons_and_skipped_entries_are_left_out() {
+        let long_a = "a".repeat(MAX_CHARS + 1);
+        let long_b = format!("{}b", "a".repeat(MAX_CHARS));
+        let entries = [
+            (1, "hello world"),
+            (2, "hello world"),
+            (3, ""),
+            (4, long_a.as_str()),
+            (5, long_b.as_str()),
+            (6, "unrelated"),
+        ];
+        // Exact duplicates, empty entries and overly long ones aren't compared
+        assert!(clusters(&entries, 0.5).is_empty());
+    }
+
+    #[test]
+    fn larger_clusters_come_first() {
+        let entries = [(1, "aaaa"), (2, "aaab"), (3, "zzzzzz"), (4, "zzzzzy"), (5, "zzzzyy")];
+        assert_eq!(sorted(clusters(&entries, 0.6)), [vec![3, 4, 5], vec![1, 2]]);
+    }
+}
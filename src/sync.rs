@@ -0,0 +1,208 @@
+use crate::ClipboardEntry;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver};
+
+/// Watches the history file for modifications made by another machine through a
+/// shared (Syncthing, Dropbox, ...) folder.
+///
+/// The parent directory is watched rather than the file itself, because sync tools
+/// usually replace files by renaming a temporary file over them.
+pub struct SyncWatcher {
+    _watcher: RecommendedWatcher,
+    events: Receiver<()>,
+}
+
+impl SyncWatcher {
+    pub fn new(history_file: &Path) -> notify::Result<Self> {
+        let (sender, events) = channel();
+        let file_name = history_file.file_name().map(|name| name.to_owned());
+
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+            if let Ok(event) = result
+                && (event.kind.is_modify() || event.kind.is_create())
+                && event.paths.iter().any(|path| path.file_name() == file_name.as_deref()) {
+                let _ = sender.send(());
+            }
+        })?;
+
+        let directory = history_file.parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        watcher.watch(directory, RecursiveMode::NonRecursive)?;
+
+        Ok(Self { _watcher: watcher, events })
+    }
+
+    /// Whether the history file was touched since the last call.
+    pub fn changed(&self) -> bool {
+        self.events.try_iter().count() > 0
+    }
+}
+
+/// Three-way merge of the local history with the copy found on disk.
+///
+/// `base` holds the content hashes both sides agreed on at the last sync, which
+/// tells a deletion on one side apart from an addition on the other:
+/// - entries on both sides are kept, with the most recently changed favorite flag winning;
+/// - entries on only one side are kept if they are new since the last sync, and
+///   dropped if they were in `base` (the other side deleted them).
+///
+/// The result is ordered newest first, with ties broken by content so that both
+/// machines produce identical files and stop rewriting each other.
+pub fn merge(
+    base: &HashSet<u64>,
+    local: VecDeque<ClipboardEntry>,
+    remote: VecDeque<ClipboardEntry>,
+) -> VecDeque<ClipboardEntry> {
+    let mut remote_by_hash: HashMap<u64, ClipboardEntry> = remote.into_iter()
+        .map(|entry| (crate::content_hash(&entry.content), entry))
+        .collect();
+
+    let mut merged = Vec::new();
+    for mut entry in local {
+        let hash = crate::content_hash(&entry.content);
+        match remote_by_hash.remove(&hash) {
+            Some(theirs) => {
+                if theirs.favorite_changed_at > entry.favorite_changed_at {
+                    entry.favorite = theirs.favorite;
+                    entry.favorite_changed_at = theirs.favorite_changed_at;
+                }
+                merged.push(entry);
+            }
+            None if !base.contains(&hash) => merged.push(entry),
+            None => {} // Deleted on the other machine
+        }
+    }
+
+    // Whatever is left only exists remotely
+    merged.extend(remote_by_hash.into_iter()
+        .filter(|(hash, _)| !base.contains(hash))
+        .map(|(_, entry)| entry));
+
+    merged.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.content.cmp(&b.content)));
+    merged.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(content, timestamp, favorite, favorite_changed_at)`
+    type Row = (&'static str, u64, bool, u64);
+
+    fn side(rows: &[Row]) -> VecDeque<ClipboardEntry> {
+        rows.iter().map(|&(content, timestamp, favorite, changed_at)| {
+            let mut entry = ClipboardEntry::new(content.to_string());
+            entry.timestamp = timestamp;
+            entry.favorite = favorite;
+            entry.favorite_changed_at = changed_at;
+            entry
+        }).collect()
+    }
+
+    #[test]
+    fn merge_cases() {
+        struct Case {
+            name: &'static str,
+            base: &'static [&'static str],
+            local: &'static [Row],
+            remote: &'static [Row],
+            /// `(content, favorite)`, newest first
+            expected: &'static [(&'static str, bool)],
+        }
+
+        let cases = [
+            Case {
+                name: "unchanged on both sides",
+                base: &["a"],
+                local: &[("a", 1, false, 0)],
+                remote: &[("a", 1, false, 0)],
+                expected: &[("a", false)],
+            },
+            Case {
+                name: "added locally",
+                base: &["a"],
+                local: &[("new", 2, false, 0), ("a", 1, false, 0)],
+                remote: &[("a", 1, false, 0)],
+                expected: &[("new", false), ("a", false)],
+            },
+            Case {
+                name: "added remotely",
+                base: &["a"],
+                local: &[("a", 1, false, 0)],
+                remote: &[("new", 2, false, 0), ("a", 1, false, 0)],
+                expected: &[("new", false), ("a", false)],
+            },
+            Case {
+                name: "added on both sides",
+                base: &[],
+                local: &[("mine", 2, false, 0)],
+                remote: &[("theirs", 3, false, 0)],
+                expected: &[("theirs", false), ("mine", false)],
+            },
+            Case {
+                name: "deleted locally",
+                base: &["a", "b"],
+                local: &[("b", 2, false, 0)],
+                remote: &[("b", 2, false, 0), ("a", 1, false, 0)],
+                expected: &[("b", false)],
+            },
+            Case {
+                name: "deleted remotely",
+                base: &["a", "b"],
+                local: &[("b", 2, false, 0), ("a", 1, false, 0)],
+                remote: &[("b", 2, false, 0)],
+                expected: &[("b", false)],
+            },
+            Case {
+                name: "deleted on both sides",
+                base: &["a", "b"],
+                local: &[("b", 2, false, 0)],
+                remote: &[("b", 2, false, 0)],
+                expected: &[("b", false)],
+            },
+            Case {
+                name: "favorited remotely after the local change",
+                base: &["a"],
+                local: &[("a", 1, false, 10)],
+                remote: &[("a", 1, true, 20)],
+                expected: &[("a", true)],
+            },
+            Case {
+                name: "unfavorited locally after the remote change",
+                base: &["a"],
+                local: &[("a", 1, false, 30)],
+                remote: &[("a", 1, true, 20)],
+                expected: &[("a", false)],
+            },
+            Case {
+                name: "favorited on an entry new to both",
+                base: &[],
+                local: &[("a", 1, true, 5)],
+                remote: &[("a", 1, false, 0)],
+                expected: &[("a", true)],
+            },
+            Case {
+                name: "equal timestamps order by content",
+                base: &[],
+                local: &[("b", 1, false, 0)],
+                remote: &[("a", 1, false, 0)],
+                expected: &[("a", false), ("b", false)],
+            },
+        ];
+
+        for case in cases {
+            let base = case.base.iter().map(|content| crate::content_hash(content)).collect();
+            let merged = merge(&base, side(case.local), side(case.remote));
+            let result: Vec<(&str, bool)> = merged.iter().map(|entry| (entry.content.as_str(), entry.favorite)).collect();
+            assert_eq!(result, case.expected, "{}", case.name);
+
+            // Both machines must write the same file
+            let reversed = merge(&base, side(case.remote), side(case.local));
+            let result: Vec<(&str, bool)> = reversed.iter().map(|entry| (entry.content.as_str(), entry.favorite)).collect();
+            assert_eq!(result, case.expected, "{} (from the other side)", case.name);
+        }
+    }
+}
@@ -0,0 +1,376 @@
+/// A text transformation that can be applied to an entry when copying it out.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Transform {
+    Trim,
+    Uppercase,
+    Lowercase,
+    StripAnsi,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 4] = [Transform::Trim, Transform::Uppercase, Transform::Lowercase, Transform::StripAnsi];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Transform::Trim => "Trimmed",
+            Transform::Uppercase => "UPPERCASE",
+            Transform::Lowercase => "lowercase",
+            Transform::StripAnsi => "Without ANSI codes",
+        }
+    }
+
+    pub fn apply(self, input: &str) -> String {
+        match self {
+            Transform::Trim => input.trim().to_string(),
+            Transform::Uppercase => input.to_uppercase(),
+            Transform::Lowercase => input.to_lowercase(),
+            Transform::StripAnsi => strip_ansi(input),
+        }
+    }
+}
+
+/// Remove ANSI escape sequences (colors, cursor movement, window titles) as
+/// found in text copied from a terminal.
+pub fn strip_ansi(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates up to a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: up to BEL or ST (ESC \)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // Two-character sequences such as ESC ( B or ESC =
+            Some('(' | ')') => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Expand tabs in each line's leading indentation to spaces, up to the next
+/// multiple of `width`. Tabs and spaces after the indentation are kept.
+pub fn tabs_to_spaces(input: &str, width: usize) -> String {
+    reindent(input, width, |column| " ".repeat(column))
+}
+
+/// Replace each line's leading indentation with tabs of `width` columns, padding
+/// any remainder with spaces. Spaces after the indentation (alignment) are kept.
+pub fn spaces_to_tabs(input: &str, width: usize) -> String {
+    reindent(input, width, |column| format!("{}{}", "\t".repeat(column / width), " ".repeat(column % width)))
+}
+
+/// Rewrite the leading indentation of every line, given its width in columns.
+fn reindent(input: &str, width: usize, indent: impl Fn(usize) -> String) -> String {
+    let width = width.max(1);
+    let mut result = String::with_capacity(input.len());
+    for line in input.split_inclusive('\n') {
+        let body = line.trim_start_matches([' ', '\t']);
+        let column = line[..line.len() - body.len()].chars().fold(0, |column, c| match c {
+            '\t' => (column / width + 1) * width,
+            _ => column + 1,
+        });
+        // Whitespace-only lines keep whatever they had
+        if body.trim_end_matches(['\r', '\n']).is_empty() {
+            result.push_str(line);
+        } else {
+            result.push_str(&indent(column));
+            result.push_str(body);
+        }
+    }
+    result
+}
+
+/// Remove NULs and other control characters, keeping tabs and line breaks
+/// (`\n`, and `\r` as in CRLF).
+pub fn strip_control_chars(input: &str) -> String {
+    input.chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect()
+}
+
+/// Wrap `content` in a quick-wrap preset's prefix and suffix.
+pub fn wrap(content: &str, prefix: &str, suffix: &str) -> String {
+    format!("{}{}{}", prefix, content, suffix)
+}
+
+/// Convert a Windows path to its WSL/Unix form: `C:\Users\me` becomes
+/// `/mnt/c/Users/me` and `\\server\share` becomes `//server/share`.
+/// Returns `None` if `path` doesn't look like a Windows path.
+pub fn windows_to_unix_path(path: &str) -> Option<String> {
+    map_quoted(path, |path| {
+        if path.contains('\n') || path.contains('/') {
+            return None;
+        }
+
+        if let Some(unc) = path.strip_prefix(r"\\") {
+            return (!unc.is_empty()).then(|| format!("//{}", unc.replace('\\', "/")));
+        }
+
+        let mut chars = path.chars();
+        let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+        let rest = chars.as_str().strip_prefix(':')?;
+        if !rest.is_empty() && !rest.starts_with('\\') {
+            return None; // Drive-relative paths like `C:foo` have no Unix equivalent
+        }
+        Some(format!("/mnt/{}{}", drive.to_ascii_lowercase(), rest.replace('\\', "/")))
+    })
+}
+
+/// Convert a WSL/Unix path to its Windows form: `/mnt/c/Users/me` becomes
+/// `C:\Users\me` and `//server/share` becomes `\\server\share`.
+/// Returns `None` for paths without a Windows equivalent.
+pub fn unix_to_windows_path(path: &str) -> Option<String> {
+    map_quoted(path, |path| {
+        if path.contains('\n') || path.contains('\\') {
+            return None;
+        }
+
+        if let Some(unc) = path.strip_prefix("//") {
+            return (!unc.is_empty() && !unc.starts_with('/')).then(|| format!(r"\\{}", unc.replace('/', "\\")));
+        }
+
+        let rest = path.strip_prefix("/mnt/")?;
+        let mut chars = rest.chars();
+        let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+        let rest = chars.as_str();
+        if !rest.is_empty() && !rest.starts_with('/') {
+            return None; // e.g. `/mnt/data`, not a drive mount
+        }
+        Some(format!("{}:{}", drive.to_ascii_uppercase(), if rest.is_empty() { "\\".to_string() } else { rest.replace('/', "\\") }))
+    })
+}
+
+/// Apply `convert` to a trimmed path, keeping surrounding double quotes (used for
+/// paths with spaces) intact.
+fn map_quoted(path: &str, convert: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let path = path.trim();
+    match path.strip_prefix('"').and_then(|inner| inner.strip_suffix('"')) {
+        Some(inner) => convert(inner).map(|converted| format!("\"{}\"", converted)),
+        None => convert(path),
+    }
+}
+
+/// Extract the lines selected by `spec`, either a single line (`"5"`) or an
+/// inclusive range (`"3-7"`), counted from 1. Ranges past the end are clamped;
+/// `None` if the spec is malformed or selects nothing.
+pub fn extract_lines(content: &str, spec: &str) -> Option<String> {
+    let (start, end) = match spec.split_once('-') {
+        Some((start, end)) => (start.trim().parse::<usize>().ok()?, end.trim().parse::<usize>().ok()?),
+        None => {
+            let line = spec.trim().parse::<usize>().ok()?;
+            (line, line)
+        }
+    };
+    if start == 0 || end < start {
+        return None;
+    }
+
+    let lines: Vec<&str> = content.lines().skip(start - 1).take(end - start + 1).collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Query parameters that only serve to track where a link was shared. A trailing
+/// `*` matches any parameter with that prefix.
+pub const DEFAULT_TRACKING_PARAMS: [&str; 10] = [
+    "utm_*", "fbclid", "gclid", "dclid", "msclkid", "mc_cid", "mc_eid", "igshid", "yclid", "_ga",
+];
+
+/// Remove tracking query parameters matching `params` from an http(s) URL,
+/// keeping any fragment. `None` if `url` isn't a single URL or has nothing to strip.
+pub fn strip_tracking_params(url: &str, params: &[String]) -> Option<String> {
+    let url = url.trim();
+    let lower = url.to_ascii_lowercase();
+    if !(lower.starts_with("http://") || lower.starts_with("https://")) || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let (url, fragment) = match url.split_once('#') {
+        Some((url, fragment)) => (url, Some(fragment)),
+        None => (url, None),
+    };
+    let (base, query) = url.split_once('?')?;
+
+    let is_tracking = |pair: &str| {
+        let name = pair.split('=').next().unwrap_or_default();
+        params.iter().any(|param| match param.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => name == param,
+        })
+    };
+    let kept: Vec<&str> = query.split('&').filter(|pair| !pair.is_empty() && !is_tracking(pair)).collect();
+    if kept.len() == query.split('&').filter(|pair| !pair.is_empty()).count() {
+        return None;
+    }
+
+    let mut clean = base.to_string();
+    if !kept.is_empty() {
+        clean.push('?');
+        clean.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        clean.push('#');
+        clean.push_str(fragment);
+    }
+    Some(clean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_drive_paths() {
+        assert_eq!(windows_to_unix_path(r"C:\Users\me").as_deref(), Some("/mnt/c/Users/me"));
+        assert_eq!(windows_to_unix_path(r"d:\").as_deref(), Some("/mnt/d/"));
+        assert_eq!(windows_to_unix_path("E:").as_deref(), Some("/mnt/e"));
+        assert_eq!(unix_to_windows_path("/mnt/c/Users/me").as_deref(), Some(r"C:\Users\me"));
+        assert_eq!(unix_to_windows_path("/mnt/d").as_deref(), Some(r"D:\"));
+    }
+
+    #[test]
+    fn converts_unc_paths() {
+        assert_eq!(windows_to_unix_path(r"\\server\share\dir").as_deref(), Some("//server/share/dir"));
+        assert_eq!(unix_to_windows_path("//server/share/dir").as_deref(), Some(r"\\server\share\dir"));
+        assert_eq!(windows_to_unix_path(r"\\"), None);
+        assert_eq!(unix_to_windows_path("///etc"), None);
+    }
+
+    #[test]
+    fn keeps_spaces_and_quotes() {
+        assert_eq!(windows_to_unix_path(r"C:\Program Files\App").as_deref(), Some("/mnt/c/Program Files/App"));
+        assert_eq!(windows_to_unix_path(r#""C:\Program Files\App""#).as_deref(), Some(r#""/mnt/c/Program Files/App""#));
+        assert_eq!(unix_to_windows_path(r#""/mnt/c/My Documents""#).as_deref(), Some(r#""C:\My Documents""#));
+    }
+
+    #[test]
+    fn rejects_paths_without_an_equivalent() {
+        assert_eq!(windows_to_unix_path("C:foo"), None);
+        assert_eq!(windows_to_unix_path("/usr/bin"), None);
+        assert_eq!(windows_to_unix_path("just text"), None);
+        assert_eq!(unix_to_windows_path("/usr/bin"), None);
+        assert_eq!(unix_to_windows_path("/mnt/data"), None);
+        assert_eq!(unix_to_windows_path(r"C:\Users"), None);
+    }
+
+    fn default_params() -> Vec<String> {
+        DEFAULT_TRACKING_PARAMS.iter().map(|param| param.to_string()).collect()
+    }
+
+    #[test]
+    fn strips_a_query_of_only_tracking_params() {
+        let url = "https://example.com/page?utm_source=x&utm_medium=y&fbclid=z";
+        assert_eq!(strip_tracking_params(url, &default_params()).as_deref(), Some("https://example.com/page"));
+    }
+
+    #[test]
+    fn keeps_other_params_in_order() {
+        let url = "https://example.com/search?q=rust&utm_campaign=spring&page=2&gclid=abc";
+        assert_eq!(strip_tracking_params(url, &default_params()).as_deref(), Some("https://example.com/search?q=rust&page=2"));
+    }
+
+    #[test]
+    fn keeps_the_fragment() {
+        let url = "https://example.com/doc?utm_source=feed#section-2";
+        assert_eq!(strip_tracking_params(url, &default_params()).as_deref(), Some("https://example.com/doc#section-2"));
+    }
+
+    #[test]
+    fn leaves_urls_without_tracking_alone() {
+        assert_eq!(strip_tracking_params("https://example.com/page", &default_params()), None);
+        assert_eq!(strip_tracking_params("https://example.com/page#top", &default_params()), None);
+        assert_eq!(strip_tracking_params("https://example.com/?q=1", &default_params()), None);
+        assert_eq!(strip_tracking_params("ftp://example.com/?utm_source=x", &default_params()), None);
+        assert_eq!(strip_tracking_params("see https://example.com/?utm_source=x", &default_params()), None);
+    }
+
+    #[test]
+    fn wraps_content_in_prefix_and_suffix() {
+        assert_eq!(wrap("value", "\"", "\""), "\"value\"");
+        assert_eq!(wrap("let x = 1;", "```rust\n", "\n```"), "```rust\nlet x = 1;\n```");
+        assert_eq!(wrap("a\nb", "", ""), "a\nb");
+        assert_eq!(wrap("", "(", ")"), "()");
+    }
+
+    #[test]
+    fn strips_csi_colors_and_cursor_codes() {
+        assert_eq!(strip_ansi("\x1b[1;31merror\x1b[0m: failed"), "error: failed");
+        assert_eq!(strip_ansi("\x1b[2K\x1b[1Gdone"), "done");
+        assert_eq!(strip_ansi("plain text"), "plain text");
+    }
+
+    #[test]
+    fn strips_osc_ending_in_bel_or_st() {
+        assert_eq!(strip_ansi("\x1b]0;title\x07prompt$ "), "prompt$ ");
+        assert_eq!(strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), "link");
+    }
+
+    #[test]
+    fn strips_charset_selection_and_a_trailing_escape() {
+        assert_eq!(strip_ansi("\x1b(Bok"), "ok");
+        assert_eq!(strip_ansi("end\x1b"), "end");
+        assert_eq!(strip_ansi("\x1b[31"), "");
+    }
+
+    #[test]
+    fn converts_only_leading_indentation() {
+        assert_eq!(tabs_to_spaces("\tlet a =\t1;\n\t\tb", 4), "    let a =\t1;\n        b");
+        assert_eq!(spaces_to_tabs("    let a = 1;  // x\n        b", 4), "\tlet a = 1;  // x\n\t\tb");
+    }
+
+    #[test]
+    fn handles_mixed_indentation() {
+        // A tab after two spaces still ends at the next tab stop
+        assert_eq!(tabs_to_spaces("  \tx", 4), "    x");
+        assert_eq!(tabs_to_spaces("\t  x", 4), "      x");
+        assert_eq!(spaces_to_tabs("\t  x", 4), "\t  x");
+        assert_eq!(spaces_to_tabs("  \t  x", 4), "\t  x");
+        assert_eq!(tabs_to_spaces("\tx", 2), "  x");
+    }
+
+    #[test]
+    fn keeps_line_endings_and_blank_lines() {
+        assert_eq!(tabs_to_spaces("\ta\r\n\t\r\n\tb\n", 4), "    a\r\n\t\r\n    b\n");
+        assert_eq!(spaces_to_tabs("", 4), "");
+    }
+
+    #[test]
+    fn round_trips() {
+        let tabs = "fn main() {\n\tif x {\n\t\ty();\n\t}\n}\n";
+        assert_eq!(spaces_to_tabs(&tabs_to_spaces(tabs, 4), 4), tabs);
+        let spaces = "def f():\n    return [\n        1,\n    ]\n";
+        assert_eq!(tabs_to_spaces(&spaces_to_tabs(spaces, 4), 4), spaces);
+    }
+
+    #[test]
+    fn strips_control_characters_but_keeps_line_breaks_and_tabs() {
+        assert_eq!(strip_control_chars("a\tb\nc\r\nd"), "a\tb\nc\r\nd");
+        assert_eq!(strip_control_chars("nul\0byte"), "nulbyte");
+        assert_eq!(strip_control_chars("\x01\x07bell\x08\x1b\x7f"), "bell");
+        // C1 controls
+        assert_eq!(strip_control_chars("a\u{85}b\u{9b}c"), "abc");
+        assert_eq!(strip_control_chars("héllo ✓"), "héllo ✓");
+    }
+}
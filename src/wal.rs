@@ -0,0 +1,194 @@
+//! Append-only change log kept next to the history snapshot.
+//!
+//! Small mutations are appended as one JSON record per line instead of rewriting
+//! the whole history file. On startup the log is replayed over the snapshot, and
+//! `save_history` compacts it by writing a fresh snapshot and removing the log.
+
+use crate::ClipboardEntry;
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Serialize, Deserialize)]
+pub enum Change {
+    Add(ClipboardEntry),
+    Delete(u64),
+    SetFavorite { id: u64, favorite: bool, changed_at: u64 },
+    Copied(u64),
+    SetLocked { id: u64, locked: bool },
+}
+
+/// The log belonging to a history file, e.g. `clipboard_history.json.wal`.
+pub fn log_path(history_file: &Path) -> PathBuf {
+    let mut name = history_file.as_os_str().to_owned();
+    name.push(".wal");
+    PathBuf::from(name)
+}
+
+/// Append a change and return the log's size afterwards.
+pub fn append(path: &Path, change: &Change) -> io::Result<u64> {
+    let mut line = serde_json::to_string(change)?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    crate::restrict_permissions(&file)?;
+    file.write_all(line.as_bytes())?;
+    file.sync_data()?;
+    Ok(file.metadata()?.len())
+}
+
+/// Apply the logged changes to `history`, returning how many were applied.
+///
+/// Replay stops at the first unreadable line: only the last append can be torn
+/// by a crash, and everything before it is still good.
+pub fn replay(path: &Path, history: &mut VecDeque<ClipboardEntry>) -> usize {
+    let Ok(content) = fs::read_to_string(path) else {
+        return 0;
+    };
+
+    let mut applied = 0;
+    for line in content.lines() {
+        let Ok(change) = serde_json::from_str::<Change>(line) else {
+            eprintln!("Stopped replaying history log at an unreadable record");
+            break;
+        };
+        apply(change, history);
+        applied += 1;
+    }
+    applied
+}
+
+fn apply(change: Change, history: &mut VecDeque<ClipboardEntry>) {
+    match change {
+        Change::Add(entry) => {
+            // Already present if we crashed between writing a snapshot and clearing the log
+            if !history.iter().any(|e| e.id == entry.id && e.content == entry.content) {
+                history.push_front(entry);
+            }
+        }
+        Change::Delete(id) => {
+            if let Some(index) = history.iter().position(|e| e.id == id) {
+                history.remove(index);
+            }
+        }
+        Change::SetFavorite { id, favorite, changed_at } => {
+            if let Some(entry) = history.iter_mut().find(|e| e.id == id) {
+                entry.favorite = favorite;
+                entry.favorite_changed_at = changed_at;
+            }
+        }
+        Change::Copied(id) => {
+            if let Some(entry) = history.iter_mut().find(|e| e.id == id) {
+                entry.copy_count += 1;
+            }
+        }
+        Change::SetLocked { id, locked } => {
+            if let Some(entry) = history.iter_mut().find(|e| e.id == id) {
+                entry.locked = locked;
+            }
+        }
+    }
+}
+
+/// Remove the log once its changes are part of a snapshot.
+pub fn clear(path: &Path) {
+    if let Err(e) = fs::remove_file(path)
+        && e.kind() != io::ErrorKind::NotFound {
+        eprintln!("Failed to remove history log: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A log file in the temp directory, removed again on drop.
+    struct TempLog(PathBuf);
+
+    impl TempLog {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("copycat-test-{}-{}.wal", std::process::id(), name));
+            let _ = fs::remove_file(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TempLog {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn entry(id: u64, content: &str) -> ClipboardEntry {
+        let mut entry = ClipboardEntry::new(content.to_string());
+        entry.id = id;
+        entry
+    }
+
+    #[test]
+    fn logged_changes_replay_in_order() {
+        let log = TempLog::new("round-trip");
+        let changes = [
+            Change::Add(entry(1, "first")),
+            Change::Add(entry(2, "second")),
+            Change::Add(entry(3, "third")),
+            Change::SetFavorite { id: 2, favorite: true, changed_at: 42 },
+            Change::Copied(1),
+            Change::Copied(1),
+            Change::SetLocked { id: 1, locked: true },
+            Change::Delete(3),
+        ];
+        for change in &changes {
+            let size = append(&log.0, change).unwrap();
+            assert_eq!(size, fs::metadata(&log.0).unwrap().len());
+        }
+
+        let mut history = VecDeque::new();
+        assert_eq!(replay(&log.0, &mut history), changes.len());
+        let ids: Vec<u64> = history.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, [2, 1]);
+        assert!(history[0].favorite);
+        assert_eq!(history[0].favorite_changed_at, 42);
+        assert_eq!(history[1].copy_count, 2);
+        assert!(history[1].locked);
+    }
+
+    #[test]
+    fn replay_stops_at_a_torn_last_line() {
+        let log = TempLog::new("torn");
+        append(&log.0, &Change::Add(entry(1, "kept"))).unwrap();
+        append(&log.0, &Change::Copied(1)).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&log.0).unwrap();
+        file.write_all(br#"{"Add":{"id":2,"content":"cut of"#).unwrap();
+
+        let mut history = VecDeque::new();
+        assert_eq!(replay(&log.0, &mut history), 2);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].content, "kept");
+        assert_eq!(history[0].copy_count, 1);
+    }
+
+    #[test]
+    fn compaction_leaves_nothing_to_replay() {
+        let log = TempLog::new("compaction");
+        append(&log.0, &Change::Add(entry(1, "first"))).unwrap();
+        append(&log.0, &Change::Add(entry(2, "second"))).unwrap();
+        let mut snapshot = VecDeque::new();
+        replay(&log.0, &mut snapshot);
+
+        // Crashing after the snapshot is written but before the log is cleared
+        // must not add the entries twice
+        let mut reloaded = snapshot.clone();
+        assert_eq!(replay(&log.0, &mut reloaded), 2);
+        assert_eq!(reloaded.len(), 2);
+
+        clear(&log.0);
+        assert!(!log.0.exists());
+        assert_eq!(replay(&log.0, &mut reloaded), 0);
+        assert_eq!(reloaded.len(), 2);
+        // Clearing an already removed log is fine
+        clear(&log.0);
+    }
+}